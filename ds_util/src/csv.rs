@@ -1,22 +1,406 @@
-use crate::dataframe::DataFrame;
+use crate::dataframe::{Column, DataFrame, check_row_range};
+use crate::error::{CsvParseError, DsError};
+use crate::memory::MemDataFrame;
+use crate::parquet::column_array;
+use arrow::array::AsArray;
+use arrow::compute::cast;
+use arrow::compute::kernels::cast_utils::string_to_timestamp_nanos;
+use arrow::datatypes::{DataType, TimeUnit};
 use std::collections::{HashMap, HashSet};
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, Read};
+use std::ops::Range;
 
 const SEPARATOR: char = ',';
 
-pub fn read_csv<S: Into<String>>(
+/// Delimiters tried by `CsvReadOptions::sniff_delimiter`, in order of preference
+const SNIFF_CANDIDATES: [char; 4] = [',', '\t', ';', '|'];
+/// Bytes read from the start of the file to sniff the delimiter
+const SNIFF_BYTES: u64 = 64 * 1024;
+/// Data rows sampled per column to infer its type
+const INFER_ROWS: usize = 1000;
+
+/// Dialect and layout of a CSV file, built with chained setters:
+/// `CsvReadOptions::new().delimiter(';').skip_rows(2)`
+#[derive(Debug, Clone)]
+pub struct CsvReadOptions {
+    /// None to sniff the delimiter from the start of the file
+    delimiter: Option<char>,
+    quote: Option<char>,
+    escape: Option<char>,
+    comment: Option<String>,
+    skip_rows: usize,
+    header_row: Option<usize>,
+    column_names: Option<Vec<String>>,
+    trim: bool,
+    max_rows: Option<usize>,
+    infer_rows: usize,
+    column_types: HashMap<String, DataType>,
+    strict: bool,
+    na_values: HashSet<String>,
+    column_na_values: HashMap<String, HashSet<String>>,
+}
+
+impl Default for CsvReadOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: Some(SEPARATOR),
+            quote: Some('"'),
+            escape: None,
+            comment: None,
+            skip_rows: 0,
+            header_row: Some(0),
+            column_names: None,
+            trim: true,
+            max_rows: None,
+            infer_rows: INFER_ROWS,
+            column_types: HashMap::new(),
+            strict: false,
+            na_values: HashSet::new(),
+            column_na_values: HashMap::new(),
+        }
+    }
+}
+
+impl CsvReadOptions {
+    /// Comma-separated, `"`-quoted, header in the first row, fields trimmed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Field delimiter, e.g. `'\t'`, `';'` or `'|'`
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Pick the delimiter among `,`, tab, `;` and `|` from the first lines of the file
+    pub fn sniff_delimiter(mut self) -> Self {
+        self.delimiter = None;
+        self
+    }
+
+    /// Quote character; fields enclosed in it may hold delimiters and line breaks
+    pub fn quote(mut self, quote: char) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    /// Treat quote characters as ordinary text
+    pub fn no_quoting(mut self) -> Self {
+        self.quote = None;
+        self
+    }
+
+    /// Escape character that makes the next character literal, e.g. `'\\'`
+    pub fn escape(mut self, escape: char) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Skip lines starting with `prefix`, e.g. `"#"`
+    pub fn comment(mut self, prefix: impl Into<String>) -> Self {
+        self.comment = Some(prefix.into());
+        self
+    }
+
+    /// Number of lines to skip at the start of the file, before the header
+    pub fn skip_rows(mut self, rows: usize) -> Self {
+        self.skip_rows = rows;
+        self
+    }
+
+    /// 0-based index of the header row among the records after `skip_rows`;
+    /// earlier records are dropped
+    pub fn header_row(mut self, row: usize) -> Self {
+        self.header_row = Some(row);
+        self
+    }
+
+    /// The file has no header row; columns are named `col1`, `col2`, ...
+    pub fn no_header(mut self) -> Self {
+        self.header_row = None;
+        self
+    }
+
+    /// Column names to use instead of a header row. The file is then read as
+    /// having no header, unless `header_row` is set afterwards to replace it.
+    pub fn column_names<S: Into<String>>(mut self, names: Vec<S>) -> Self {
+        self.column_names = Some(names.into_iter().map(Into::into).collect());
+        self.header_row = None;
+        self
+    }
+
+    /// Trim whitespace around unquoted fields (on by default)
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Read at most `rows` data rows
+    pub fn max_rows(mut self, rows: usize) -> Self {
+        self.max_rows = Some(rows);
+        self
+    }
+
+    /// Infer column types from the first `rows` data rows (1000 by default);
+    /// 0 reads every column as Utf8. Later values that do not parse widen the
+    /// column, or are errors in strict reads and `read_csv_report`.
+    pub fn infer_rows(mut self, rows: usize) -> Self {
+        self.infer_rows = rows;
+        self
+    }
+
+    /// Types of the named columns, overriding inference. Supported types are
+    /// Int64, Float64, Boolean, Utf8 and Timestamp.
+    pub fn column_types<S: Into<String>>(mut self, types: HashMap<S, DataType>) -> Self {
+        self.column_types = types.into_iter().map(|(k, v)| (k.into(), v)).collect();
+        self
+    }
+
+    /// Fail on the first row whose field count differs from the header and on
+    /// the first value that does not parse as its column's type, given in
+    /// `column_types` or inferred from the `infer_rows` sample, instead of
+    /// padding rows, adding `colN` columns and widening or parsing leniently.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Field texts read as NULL in every column, e.g. `NA`, `null` or `-999`,
+    /// compared after trimming. Empty fields are always missing in non-string
+    /// columns; in string columns they stay empty strings unless `""` is listed.
+    pub fn na_values<S: Into<String>>(mut self, tokens: Vec<S>) -> Self {
+        self.na_values = tokens.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Field texts read as NULL in `column`, in addition to `na_values`
+    pub fn column_na_values<S: Into<String>>(
+        mut self,
+        column: impl Into<String>,
+        tokens: Vec<S>,
+    ) -> Self {
+        self.column_na_values
+            .insert(column.into(), tokens.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+/// How malformed rows and values are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Checking {
+    Lenient,
+    /// Fail with the problem on the earliest line
+    Strict,
+    /// Return every problem alongside the frame
+    Collect,
+}
+
+impl Checking {
+    /// Strict or lenient, as `options` ask
+    fn of(options: &CsvReadOptions) -> Checking {
+        if options.strict {
+            Checking::Strict
+        } else {
+            Checking::Lenient
+        }
+    }
+}
+
+/// Problems found while reading one file. Checked reads record them all, so
+/// that strict reads can fail on the earliest line once types are known;
+/// lenient reads fail on the first.
+struct Issues<'a> {
+    path: &'a str,
+    checking: Checking,
+    errors: Vec<CsvParseError>,
+}
+
+impl Issues<'_> {
+    fn report(
+        &mut self,
+        line: usize,
+        column: Option<&str>,
+        value: Option<&str>,
+        message: String,
+    ) -> Result<(), DsError> {
+        let error = CsvParseError {
+            path: self.path.to_string(),
+            line,
+            column: column.map(str::to_string),
+            value: value.map(str::to_string),
+            message,
+        };
+        if self.checking == Checking::Lenient {
+            Err(DsError::CsvParse(error))
+        } else {
+            self.errors.push(error);
+            Ok(())
+        }
+    }
+}
+
+/// Read a CSV file; see `CsvReadOptions` for the dialect, types and strictness
+pub fn read_csv(path: &str, options: &CsvReadOptions) -> Result<Box<dyn DataFrame>, DsError> {
+    Ok(read_csv_checked(path, options, Checking::of(options))?.0)
+}
+
+/// Read a CSV file with strict checks, returning every malformed row and bad
+/// value instead of failing on the first. Bad values are read as NULL, extra
+/// fields are dropped and missing ones are NULL. Errors that stop the read,
+/// such as an unterminated quote, are still returned as `Err`. Values are
+/// checked as in `CsvReadOptions::strict`.
+pub fn read_csv_report(
+    path: &str,
+    options: &CsvReadOptions,
+) -> Result<(Box<dyn DataFrame>, Vec<CsvParseError>), DsError> {
+    read_csv_checked(path, options, Checking::Collect)
+}
+
+/// Read CSV files as `read_csv` does, inferring each column's type from all
+/// the files together so that they agree
+pub(crate) fn read_csv_frames(
+    paths: &[&str],
+    options: &CsvReadOptions,
+) -> Result<Vec<Box<dyn DataFrame>>, DsError> {
+    let frames = read_csv_files(paths, options, Checking::of(options))?;
+    Ok(frames.into_iter().map(|(frame, _)| frame).collect())
+}
+
+/// A frame and the problems found while reading it
+type CheckedFrame = (Box<dyn DataFrame>, Vec<CsvParseError>);
+
+fn read_csv_checked(
+    path: &str,
+    options: &CsvReadOptions,
+    checking: Checking,
+) -> Result<CheckedFrame, DsError> {
+    let mut frames = read_csv_files(&[path], options, checking)?;
+    Ok(frames.swap_remove(0))
+}
+
+/// Read and type each file. A column's inferred type fits the values of
+/// every file, so files with the same columns read with the same types.
+fn read_csv_files(
+    paths: &[&str],
+    options: &CsvReadOptions,
+    checking: Checking,
+) -> Result<Vec<CheckedFrame>, DsError> {
+    let lenient = checking == Checking::Lenient;
+    let mut files = Vec::with_capacity(paths.len());
+    for path in paths {
+        let mut issues = Issues {
+            path,
+            checking,
+            errors: Vec::new(),
+        };
+        let mut text = read_text(path, options, &mut issues)?;
+        let is_na: Vec<Vec<bool>> = text
+            .headers
+            .iter()
+            .zip(&mut text.columns)
+            .map(|(name, values)| mark_na(values, options, name))
+            .collect();
+        files.push((issues, text, is_na));
+    }
+
+    let mut inferred: HashMap<String, Option<DataType>> = HashMap::new();
+    for (_, text, _) in &files {
+        for (name, values) in text.headers.iter().zip(&text.columns) {
+            if options.column_types.contains_key(name) {
+                continue;
+            }
+            let sample = &values[..values.len().min(options.infer_rows)];
+            let data_type = inferred.entry(name.clone()).or_default();
+            *data_type = merge_types(data_type.take(), infer_type(sample));
+        }
+    }
+    let mut types: HashMap<String, DataType> = HashMap::with_capacity(inferred.len());
+    for (name, data_type) in inferred {
+        let mut data_type = data_type.unwrap_or(DataType::Utf8);
+        // Values past the samples widen the column, or are reported when checking
+        if lenient {
+            for (_, text, _) in &files {
+                if let Some(i) = text.headers.iter().position(|h| *h == name) {
+                    data_type = widen_type(&text.columns[i], data_type);
+                }
+            }
+        }
+        types.insert(name, data_type);
+    }
+
+    let mut frames = Vec::with_capacity(files.len());
+    for (mut issues, text, is_na) in files {
+        let mut builder = MemDataFrame::builder();
+        for ((name, values), is_na) in text.headers.into_iter().zip(text.columns).zip(is_na) {
+            let data_type = options.column_types.get(&name).unwrap_or(&types[&name]);
+            let column = typed_column(&name, values, data_type, &text.lines, &mut issues)?;
+            builder = builder.add_column(name, with_na_strings(column, is_na));
+        }
+        let frame = CsvDataFrame {
+            frame: builder.build()?,
+            lenient,
+        };
+
+        let mut errors = issues.errors;
+        errors.sort_by_key(|e| e.line);
+        if checking == Checking::Strict && !errors.is_empty() {
+            return Err(DsError::CsvParse(errors.swap_remove(0)));
+        }
+        frames.push((Box::new(frame) as Box<dyn DataFrame>, errors));
+    }
+    Ok(frames)
+}
+
+/// Field text of a CSV file, before typing
+struct CsvText {
+    headers: Vec<String>,
+    /// Values of each column, in `headers` order
+    columns: Vec<Vec<String>>,
+    /// Line each data row starts on, for errors
+    lines: Vec<usize>,
+}
+
+/// Split the file into records and columns, reporting rows whose field
+/// count differs from the header when checking
+fn read_text(
     path: &str,
-    column_names: Option<Vec<S>>,
-) -> Result<Box<dyn DataFrame>, Box<dyn std::error::Error>> {
+    options: &CsvReadOptions,
+    issues: &mut Issues,
+) -> Result<CsvText, DsError> {
+    let checking = issues.checking;
+    let delimiter = match options.delimiter {
+        Some(delimiter) => delimiter,
+        None => sniff_delimiter(path, options)?,
+    };
     let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines();
+    let mut records = CsvRecords::new(BufReader::new(file), path, delimiter, options);
+    records.skip_lines(options.skip_rows)?;
+
+    let header = match options.header_row {
+        Some(row) => {
+            for _ in 0..row {
+                records.next_record()?;
+            }
+            let line = records.line;
+            let (_, h) = records.next_record()?.ok_or_else(|| {
+                DsError::CsvParse(CsvParseError {
+                    path: path.to_string(),
+                    line,
+                    column: None,
+                    value: None,
+                    message: "CSV is empty; cannot read header".to_string(),
+                })
+            })?;
+            Some(h)
+        }
+        None => None,
+    };
 
     // --- derive headers ---
-    let mut headers: Vec<String> = if let Some(cols) = column_names {
-        // Convert &str or String into owned Strings
-        let mut h: Vec<String> = cols.into_iter().map(Into::into).collect();
+    let mut headers: Vec<String> = if let Some(cols) = &options.column_names {
+        let mut h: Vec<String> = cols.clone();
 
         // Ensure non-empty
         for (i, name) in h.iter_mut().enumerate() {
@@ -27,8 +411,8 @@ pub fn read_csv<S: Into<String>>(
 
         // Ensure uniqueness by suffixing duplicates
         let mut seen = HashSet::new();
-        for i in 0..h.len() {
-            let base = h[i].clone();
+        for name in h.iter_mut() {
+            let base = name.clone();
             if seen.insert(base.clone()) {
                 continue;
             }
@@ -36,21 +420,14 @@ pub fn read_csv<S: Into<String>>(
             loop {
                 let cand = format!("{}_{}", base, k);
                 if seen.insert(cand.clone()) {
-                    h[i] = cand;
+                    *name = cand;
                     break;
                 }
                 k += 1;
             }
         }
         h
-    } else {
-        // Read the first line as header
-        let first_line = lines.next().ok_or("CSV is empty; cannot read header")??;
-        let mut h: Vec<String> = first_line
-            .split(SEPARATOR)
-            .map(|s| s.trim().to_string())
-            .collect();
-
+    } else if let Some(mut h) = header {
         // Ensure non-empty and unique
         for (i, name) in h.iter_mut().enumerate() {
             if name.trim().is_empty() {
@@ -58,8 +435,8 @@ pub fn read_csv<S: Into<String>>(
             }
         }
         let mut seen = HashSet::new();
-        for i in 0..h.len() {
-            let base = h[i].clone();
+        for name in h.iter_mut() {
+            let base = name.clone();
             if seen.insert(base.clone()) {
                 continue;
             }
@@ -67,31 +444,55 @@ pub fn read_csv<S: Into<String>>(
             loop {
                 let cand = format!("{}_{}", base, k);
                 if seen.insert(cand.clone()) {
-                    h[i] = cand;
+                    *name = cand;
                     break;
                 }
                 k += 1;
             }
         }
         h
+    } else {
+        // No header: columns are named as the first rows reveal them
+        Vec::new()
     };
 
     // --- storage per column (all strings initially) ---
-    let mut column_data: HashMap<String, Vec<String>> =
-        headers.iter().cloned().map(|h| (h, Vec::new())).collect();
+    let mut columns: Vec<Vec<String>> = vec![Vec::new(); headers.len()];
+    // Line each data row starts on, for errors
+    let mut lines: Vec<usize> = Vec::new();
 
-    // --- read remaining lines ---
-    for line_res in lines {
-        let line = line_res?;
-        if line.trim().is_empty() {
-            continue;
+    // --- read remaining records ---
+    while options.max_rows.is_none_or(|max| lines.len() < max) {
+        let Some((line, mut fields)) = records.next_record()? else {
+            break;
+        };
+        // Without a header the first row fixes the width when checking
+        if checking != Checking::Lenient && !headers.is_empty() {
+            if fields.len() > headers.len() {
+                issues.report(
+                    line,
+                    None,
+                    Some(&fields[headers.len()]),
+                    format!(
+                        "row has {} fields, expected {}",
+                        fields.len(),
+                        headers.len()
+                    ),
+                )?;
+                fields.truncate(headers.len());
+            } else if fields.len() < headers.len() {
+                issues.report(
+                    line,
+                    Some(&headers[fields.len()]),
+                    None,
+                    format!(
+                        "row has {} fields, expected {}",
+                        fields.len(),
+                        headers.len()
+                    ),
+                )?;
+            }
         }
-
-        let mut fields: Vec<String> = line
-            .split(SEPARATOR)
-            .map(|s| s.trim().to_string())
-            .collect();
-
         // Pad or extend if needed
         if fields.len() < headers.len() {
             fields.resize(headers.len(), String::new());
@@ -100,93 +501,586 @@ pub fn read_csv<S: Into<String>>(
             for _ in 0..extra {
                 headers.push(format!("col{}", headers.len() + 1));
             }
-            // Ensure column_data has entries for any new headers
-            for h in headers.iter().skip(column_data.len()) {
-                column_data.entry(h.clone()).or_insert_with(Vec::new);
-            }
+            // New columns are empty in the rows read so far
+            columns.resize(headers.len(), vec![String::new(); lines.len()]);
         }
 
         // Append per-column values
-        for (i, val) in fields.into_iter().enumerate() {
-            let h = &headers[i];
-            column_data.get_mut(h).unwrap().push(val);
+        for (column, val) in columns.iter_mut().zip(fields) {
+            column.push(val);
         }
+        lines.push(line);
     }
 
-    Ok(Box::new(CsvDataFrame {
-        column_order: headers,
-        column_data,
-    }))
+    Ok(CsvText {
+        headers,
+        columns,
+        lines,
+    })
 }
 
 struct CsvDataFrame {
-    column_order: Vec<String>,
-    column_data: HashMap<String, Vec<String>>,
+    frame: MemDataFrame,
+    /// Read columns of another type by parsing or formatting their values, as
+    /// `parse_i64_lossy`/`parse_f64_lossy` do, instead of failing
+    lenient: bool,
 }
 
 impl DataFrame for CsvDataFrame {
     fn shape(&self) -> (usize, usize) {
-        // rows = max column length (CSV may be ragged); cols = number of columns
-        let rows = self
-            .column_data
-            .values()
-            .map(|v| v.len())
-            .max()
-            .unwrap_or(0);
-        (rows, self.column_order.len())
+        self.frame.shape()
     }
 
     fn column_names(&self) -> Vec<String> {
-        self.column_order.clone()
+        self.frame.column_names()
     }
 
     fn column_types(&self) -> HashMap<String, String> {
-        // By design: we store everything as strings initially.
-        let mut m = HashMap::new();
-        for c in &self.column_order {
-            m.insert(c.clone(), "string".to_string());
+        self.frame.column_types()
+    }
+
+    fn read_column(&self, column_name: &str) -> Result<Column, DsError> {
+        self.frame.read_column(column_name)
+    }
+
+    fn read_column_string(&self, column_name: &str) -> Result<Vec<String>, DsError> {
+        self.read_column_string_range(column_name, 0..self.row_count())
+    }
+
+    fn read_column_string_opt(&self, column_name: &str) -> Result<Vec<Option<String>>, DsError> {
+        self.read_column_string_opt_range(column_name, 0..self.row_count())
+    }
+
+    fn read_column_i64(&self, column_name: &str) -> Result<Vec<i64>, DsError> {
+        self.read_column_i64_range(column_name, 0..self.row_count())
+    }
+
+    fn read_column_f64(&self, column_name: &str) -> Result<Vec<f64>, DsError> {
+        self.read_column_f64_range(column_name, 0..self.row_count())
+    }
+
+    fn read_index_microsecond(&self) -> Result<Vec<i64>, DsError> {
+        // Heuristic: prefer "timestamp" then "index", otherwise first column.
+        let names = self.frame.column_names();
+        let Some(first) = names.first() else {
+            return Ok(Vec::new());
+        };
+        let candidate = if names.iter().any(|n| n == "timestamp") {
+            "timestamp"
+        } else if names.iter().any(|n| n == "index") {
+            "index"
+        } else {
+            first
+        };
+        self.read_column_timestamp(candidate, TimeUnit::Microsecond)
+    }
+
+    /// Lenient frames format the values of other columns, NULL as ""
+    fn read_column_string_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<String>, DsError> {
+        if !self.lenient || self.frame.column(column_name)?.data_type() == DataType::Utf8 {
+            return self.frame.read_column_string_range(column_name, rows);
         }
-        m
+        let text = self.text(column_name, rows)?;
+        Ok(text.into_iter().map(Option::unwrap_or_default).collect())
     }
 
-    fn read_column_string(&self, column_name: &str) -> Vec<String> {
-        match self.column_data.get(column_name) {
-            Some(col) => col.to_vec(),
-            None => panic!("Column '{}' not found", column_name),
+    fn read_column_string_opt_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<Option<String>>, DsError> {
+        if !self.lenient {
+            return self.frame.read_column_string_opt_range(column_name, rows);
         }
+        self.text(column_name, rows)
     }
 
-    fn read_column_i64(&self, column_name: &str) -> Vec<i64> {
-        match self.column_data.get(column_name) {
-            Some(col) => col.iter().map(|s| parse_i64_lossy(s)).collect(),
-            None => panic!("Column '{}' not found", column_name),
+    fn read_column_i64_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<i64>, DsError> {
+        if !self.lenient || self.frame.column(column_name)?.data_type() == DataType::Int64 {
+            return self.frame.read_column_i64_range(column_name, rows);
         }
+        self.parse_text(column_name, rows, parse_i64_lossy, 0)
     }
 
-    fn read_column_f64(&self, column_name: &str) -> Vec<f64> {
-        match self.column_data.get(column_name) {
-            Some(col) => col.iter().map(|s| parse_f64_lossy(s)).collect(),
-            None => panic!("Column '{}' not found", column_name),
+    /// Int64 columns are widened, so numeric columns read alike whatever was
+    /// inferred; lenient frames parse other columns from their text
+    fn read_column_f64_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<f64>, DsError> {
+        match self.frame.column(column_name)? {
+            Column::Int64(v) => {
+                check_row_range(&rows, self.row_count())?;
+                Ok(v[rows].iter().map(|&x| x as f64).collect())
+            }
+            Column::NullableInt64(v) => {
+                check_row_range(&rows, self.row_count())?;
+                Ok(v[rows]
+                    .iter()
+                    .map(|x| x.map_or(f64::NAN, |x| x as f64))
+                    .collect())
+            }
+            Column::Float64(_) | Column::NullableFloat64(_) => {
+                self.frame.read_column_f64_range(column_name, rows)
+            }
+            _ if self.lenient => self.parse_text(column_name, rows, parse_f64_lossy, f64::NAN),
+            _ => self.frame.read_column_f64_range(column_name, rows),
         }
     }
+}
 
-    fn read_index_microsecond(&self) -> Vec<i64> {
-        // Heuristic: prefer "timestamp" then "index", otherwise first column.
-        if self.column_order.is_empty() {
-            return Vec::new();
+impl CsvDataFrame {
+    fn row_count(&self) -> usize {
+        self.frame.shape().0
+    }
+
+    /// `rows` of a column as text, NULL as None; typed values are formatted
+    fn text(&self, column_name: &str, rows: Range<usize>) -> Result<Vec<Option<String>>, DsError> {
+        let column = self.frame.column(column_name)?;
+        if column.data_type() == DataType::Utf8 {
+            return self.frame.read_column_string_opt_range(column_name, rows);
         }
-        let candidate = if self.column_data.contains_key("timestamp") {
-            "timestamp"
-        } else if self.column_data.contains_key("index") {
-            "index"
-        } else {
-            &self.column_order[0]
+        check_row_range(&rows, self.row_count())?;
+        let values = column.take(&rows.collect::<Vec<_>>());
+        let text = cast(&column_array(values, false), &DataType::Utf8)?;
+        Ok(text
+            .as_string::<i32>()
+            .iter()
+            .map(|v| v.map(str::to_string))
+            .collect())
+    }
+
+    /// Parse `rows` of a column's text with `parse`, NULL giving `null`, for
+    /// lenient reads that do not match the column's type
+    fn parse_text<T: Copy>(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+        parse: fn(&str) -> T,
+        null: T,
+    ) -> Result<Vec<T>, DsError> {
+        let text = self.text(column_name, rows)?;
+        Ok(text
+            .iter()
+            .map(|value| value.as_deref().map_or(null, parse))
+            .collect())
+    }
+}
+
+/* -------------------- type inference -------------------- */
+
+/// Blank out the NA tokens of a column so that they read as missing, returning
+/// which rows held one; empty when the column has no NA tokens
+fn mark_na(values: &mut [String], options: &CsvReadOptions, column_name: &str) -> Vec<bool> {
+    let column_tokens = options.column_na_values.get(column_name);
+    if options.na_values.is_empty() && column_tokens.is_none() {
+        return Vec::new();
+    }
+    let is_token =
+        |t: &str| options.na_values.contains(t) || column_tokens.is_some_and(|c| c.contains(t));
+    values
+        .iter_mut()
+        .map(|value| {
+            let na = is_token(value.trim());
+            if na {
+                value.clear();
+            }
+            na
+        })
+        .collect()
+}
+
+/// Make NA rows NULL in a string column; other types already read them as missing
+fn with_na_strings(column: Column, is_na: Vec<bool>) -> Column {
+    match column {
+        Column::Utf8(values) if is_na.contains(&true) => Column::NullableUtf8(
+            values
+                .into_iter()
+                .zip(is_na)
+                .map(|(value, na)| (!na).then_some(value))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// The narrowest of Int64, Float64, Boolean and Timestamp that parses every
+/// non-empty value of `sample`, else Utf8; None when every value is empty
+fn infer_type(sample: &[String]) -> Option<DataType> {
+    let values: Vec<&str> = sample
+        .iter()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if values.is_empty() {
+        return None;
+    }
+    Some(if values.iter().all(|s| s.parse::<i64>().is_ok()) {
+        DataType::Int64
+    } else if values.iter().all(|s| s.parse::<f64>().is_ok()) {
+        DataType::Float64
+    } else if values.iter().all(|s| parse_bool(s).is_some()) {
+        DataType::Boolean
+    } else if values.iter().all(|s| string_to_timestamp_nanos(s).is_ok()) {
+        DataType::Timestamp(TimeUnit::Nanosecond, None)
+    } else {
+        DataType::Utf8
+    })
+}
+
+/// The narrowest type holding values of both types: Int64 and Float64 give
+/// Float64, other differing types Utf8
+fn merge_types(a: Option<DataType>, b: Option<DataType>) -> Option<DataType> {
+    match (a, b) {
+        (None, t) | (t, None) => t,
+        (Some(a), Some(b)) if a == b => Some(a),
+        (Some(DataType::Int64 | DataType::Float64), Some(DataType::Int64 | DataType::Float64)) => {
+            Some(DataType::Float64)
+        }
+        _ => Some(DataType::Utf8),
+    }
+}
+
+/// Widen the type inferred from a sample until it parses every non-empty
+/// value: Int64 to Float64, anything else to Utf8
+fn widen_type(values: &[String], data_type: DataType) -> DataType {
+    let all_parse = |parse: &dyn Fn(&str) -> bool| {
+        values.iter().all(|s| {
+            let t = s.trim();
+            t.is_empty() || parse(t)
+        })
+    };
+    match data_type {
+        DataType::Int64 if all_parse(&|s| s.parse::<i64>().is_ok()) => DataType::Int64,
+        DataType::Int64 | DataType::Float64 if all_parse(&|s| s.parse::<f64>().is_ok()) => {
+            DataType::Float64
+        }
+        DataType::Boolean if all_parse(&|s| parse_bool(s).is_some()) => DataType::Boolean,
+        DataType::Timestamp(..) if all_parse(&|s| string_to_timestamp_nanos(s).is_ok()) => {
+            data_type
+        }
+        _ => DataType::Utf8,
+    }
+}
+
+/// Convert `values` to `data_type`, given in `CsvReadOptions::column_types` or
+/// inferred. Without checks Int64 and Float64 parse leniently
+/// as `read_column_i64`/`read_column_f64` always have; otherwise every
+/// non-empty value must parse, and is NULL when its error is collected.
+/// Empty values are NULL, except in Utf8 columns where they stay empty strings.
+fn typed_column(
+    column_name: &str,
+    values: Vec<String>,
+    data_type: &DataType,
+    lines: &[usize],
+    issues: &mut Issues,
+) -> Result<Column, DsError> {
+    let lenient = issues.checking == Checking::Lenient;
+    let mut check = |valid: &dyn Fn(&str) -> bool| -> Result<(), DsError> {
+        for (row, value) in values.iter().enumerate() {
+            if !valid(value.trim()) {
+                issues.report(
+                    lines[row],
+                    Some(column_name),
+                    Some(value),
+                    format!("cannot parse as {:?}", data_type),
+                )?;
+            }
+        }
+        Ok(())
+    };
+    let parse_i64 = |s: &str| s.parse::<i64>().ok();
+    let parse_f64 = |s: &str| s.parse::<f64>().ok();
+
+    match data_type {
+        DataType::Utf8 => Ok(Column::Utf8(values)),
+        DataType::Int64 if lenient => Ok(parse_column(
+            &values,
+            |s| Some(parse_i64_lossy(s)),
+            Column::Int64,
+            Column::NullableInt64,
+        )),
+        DataType::Float64 if lenient => Ok(parse_column(
+            &values,
+            |s| Some(parse_f64_lossy(s)),
+            Column::Float64,
+            Column::NullableFloat64,
+        )),
+        DataType::Int64 => {
+            check(&|s| s.is_empty() || parse_i64(s).is_some())?;
+            Ok(parse_column(
+                &values,
+                parse_i64,
+                Column::Int64,
+                Column::NullableInt64,
+            ))
+        }
+        DataType::Float64 => {
+            check(&|s| s.is_empty() || parse_f64(s).is_some())?;
+            Ok(parse_column(
+                &values,
+                parse_f64,
+                Column::Float64,
+                Column::NullableFloat64,
+            ))
+        }
+        DataType::Boolean => {
+            check(&|s| s.is_empty() || parse_bool(s).is_some())?;
+            Ok(parse_column(
+                &values,
+                parse_bool,
+                Column::Boolean,
+                Column::NullableBoolean,
+            ))
+        }
+        DataType::Timestamp(unit, _) => {
+            check(&|s| s.is_empty() || string_to_timestamp_nanos(s).is_ok())?;
+            // Nanoseconds are the finest unit, so coarser ones only round down
+            let per_unit = match unit {
+                TimeUnit::Second => 1_000_000_000,
+                TimeUnit::Millisecond => 1_000_000,
+                TimeUnit::Microsecond => 1_000,
+                TimeUnit::Nanosecond => 1,
+            };
+            let unit = *unit;
+            Ok(parse_column(
+                &values,
+                |s| Some(string_to_timestamp_nanos(s).ok()?.div_euclid(per_unit)),
+                |v| Column::Timestamp(unit, v),
+                |v| Column::NullableTimestamp(unit, v),
+            ))
+        }
+        other => Err(DsError::Unsupported(format!(
+            "reading CSV column '{}' as {:?}",
+            column_name, other
+        ))),
+    }
+}
+
+/// Parse every value, taking empty values and values that do not parse as
+/// NULL. Columns without NULL get the `plain` variant.
+fn parse_column<T>(
+    values: &[String],
+    parse: impl Fn(&str) -> Option<T>,
+    plain: impl FnOnce(Vec<T>) -> Column,
+    nullable: impl FnOnce(Vec<Option<T>>) -> Column,
+) -> Column {
+    let parsed: Vec<Option<T>> = values
+        .iter()
+        .map(|value| {
+            let t = value.trim();
+            if t.is_empty() { None } else { parse(t) }
+        })
+        .collect();
+    if parsed.iter().all(Option::is_some) {
+        plain(parsed.into_iter().flatten().collect())
+    } else {
+        nullable(parsed)
+    }
+}
+
+/// `true` or `false` in any case
+fn parse_bool(s: &str) -> Option<bool> {
+    if s.eq_ignore_ascii_case("true") {
+        Some(true)
+    } else if s.eq_ignore_ascii_case("false") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/* -------------------- record parser -------------------- */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldState {
+    /// Before the first character of a field
+    Start,
+    Unquoted,
+    Quoted,
+    /// After a quote inside a quoted field: either `""` or the closing quote
+    QuoteInQuoted,
+}
+
+/// Splits CSV input into records following RFC 4180: fields may be quoted,
+/// a doubled quote inside quotes is a literal quote, and quoted fields may
+/// contain the delimiter and line breaks. Blank and comment lines are skipped.
+struct CsvRecords<'a, R> {
+    reader: R,
+    /// File name used in errors
+    path: &'a str,
+    delimiter: char,
+    options: &'a CsvReadOptions,
+    /// 1-based number of the next line to read
+    line: usize,
+}
+
+impl<'a, R: BufRead> CsvRecords<'a, R> {
+    fn new(reader: R, path: &'a str, delimiter: char, options: &'a CsvReadOptions) -> Self {
+        Self {
+            reader,
+            path,
+            delimiter,
+            options,
+            line: 1,
+        }
+    }
+
+    /// Discard the next `n` lines, quoted or not
+    fn skip_lines(&mut self, n: usize) -> Result<(), DsError> {
+        let mut buf = String::new();
+        for _ in 0..n {
+            buf.clear();
+            if self.reader.read_line(&mut buf)? == 0 {
+                break;
+            }
+            self.line += 1;
+        }
+        Ok(())
+    }
+
+    /// The next record and the line it starts on, or None at the end of input
+    fn next_record(&mut self) -> Result<Option<(usize, Vec<String>)>, DsError> {
+        let trim = self.options.trim;
+        let mut fields: Vec<String> = Vec::new();
+        let mut field = String::new();
+        let mut state = FieldState::Start;
+        let mut escaped = false;
+        let mut start = self.line;
+        let mut buf = String::new();
+
+        loop {
+            buf.clear();
+            if self.reader.read_line(&mut buf)? == 0 {
+                if state == FieldState::Quoted {
+                    return Err(DsError::CsvParse(CsvParseError {
+                        path: self.path.to_string(),
+                        line: start,
+                        column: None,
+                        value: None,
+                        message: "unterminated quoted field".to_string(),
+                    }));
+                }
+                return Ok(None);
+            }
+            self.line += 1;
+
+            let at_record_start = state == FieldState::Start && fields.is_empty();
+            if at_record_start
+                && let Some(prefix) = &self.options.comment
+                && buf.trim_start().starts_with(prefix.as_str())
+            {
+                start = self.line;
+                continue;
+            }
+
+            for c in buf.chars() {
+                if escaped {
+                    field.push(c);
+                    escaped = false;
+                    if state == FieldState::Start {
+                        state = FieldState::Unquoted;
+                    }
+                    continue;
+                }
+                let is_quote = Some(c) == self.options.quote;
+                match (state, c) {
+                    (FieldState::Quoted | FieldState::Unquoted | FieldState::Start, c)
+                        if Some(c) == self.options.escape =>
+                    {
+                        escaped = true;
+                    }
+                    (FieldState::Quoted, _) if is_quote => state = FieldState::QuoteInQuoted,
+                    (FieldState::Quoted, '\r' | '\n') => {}
+                    (FieldState::Quoted, c) => field.push(c),
+                    (FieldState::QuoteInQuoted, _) if is_quote => {
+                        field.push(c);
+                        state = FieldState::Quoted;
+                    }
+                    (_, '\r' | '\n') => break,
+                    (_, c) if c == self.delimiter => {
+                        fields.push(finish_field(&mut field, state, trim));
+                        state = FieldState::Start;
+                    }
+                    (FieldState::Start, _) if is_quote => state = FieldState::Quoted,
+                    (FieldState::Start | FieldState::QuoteInQuoted, c)
+                        if trim && c.is_whitespace() => {}
+                    // Text after a closing quote is kept, as most readers do
+                    (_, c) => {
+                        field.push(c);
+                        state = FieldState::Unquoted;
+                    }
+                }
+            }
+
+            match state {
+                // The line break belongs to the quoted field, stored as \n; keep reading
+                FieldState::Quoted => field.push('\n'),
+                FieldState::Start if fields.is_empty() && field.is_empty() => start = self.line,
+                _ => {
+                    fields.push(finish_field(&mut field, state, trim));
+                    return Ok(Some((start, fields)));
+                }
+            }
+        }
+    }
+}
+
+/// Pick the candidate delimiter that splits the first lines into the same
+/// number of fields most often, preferring more fields; `,` if none splits them
+fn sniff_delimiter(path: &str, options: &CsvReadOptions) -> Result<char, DsError> {
+    let mut bytes = Vec::new();
+    File::open(path)?
+        .take(SNIFF_BYTES)
+        .read_to_end(&mut bytes)?;
+    // The sample may end inside a multi-byte character; sniff the text before it
+    let sample = match std::str::from_utf8(&bytes) {
+        Ok(sample) => sample,
+        Err(e) => std::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default(),
+    };
+
+    let mut best = (SEPARATOR, 0, 0);
+    for candidate in SNIFF_CANDIDATES {
+        let sniff_options = CsvReadOptions {
+            delimiter: Some(candidate),
+            ..options.clone()
         };
-        self.read_column_i64(candidate)
+        let mut records = CsvRecords::new(sample.as_bytes(), path, candidate, &sniff_options);
+        if records.skip_lines(options.skip_rows).is_err() {
+            continue;
+        }
+        // Lines are sampled whole except the last, which may be cut off
+        let mut widths: HashMap<usize, usize> = HashMap::new();
+        while let Ok(Some((_, fields))) = records.next_record() {
+            *widths.entry(fields.len()).or_default() += 1;
+        }
+        let (width, count) = widths
+            .into_iter()
+            .max_by_key(|&(width, count)| (count, width))
+            .unwrap_or((1, 0));
+        if width > 1 && (count, width) > (best.1, best.2) {
+            best = (candidate, count, width);
+        }
     }
+    Ok(best.0)
+}
 
-    fn read_timestamp_second(&self) -> Vec<i64> {
-        panic!("Not implemented.");
+/// Take the completed field, trimming trailing whitespace of unquoted text if `trim`
+fn finish_field(field: &mut String, state: FieldState, trim: bool) -> String {
+    let value = std::mem::take(field);
+    if trim && state == FieldState::Unquoted {
+        value.trim_end().to_string()
+    } else {
+        value
     }
 }
 
@@ -216,3 +1110,277 @@ fn parse_f64_lossy(s: &str) -> f64 {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records of `input` read with `options`, with the line each starts on
+    fn records(
+        input: &str,
+        options: &CsvReadOptions,
+    ) -> Result<Vec<(usize, Vec<String>)>, DsError> {
+        let mut records = CsvRecords::new(input.as_bytes(), "test.csv", SEPARATOR, options);
+        let mut out = Vec::new();
+        while let Some(record) = records.next_record()? {
+            out.push(record);
+        }
+        Ok(out)
+    }
+
+    /// Write `text` to a CSV file under the system temp dir, returning its path
+    fn temp_csv(name: &str, text: &str) -> String {
+        let path = std::env::temp_dir()
+            .join(format!("ds_util_{}_{}.csv", name, std::process::id()))
+            .to_string_lossy()
+            .to_string();
+        std::fs::write(&path, text).unwrap();
+        path
+    }
+
+    fn fields(input: &str) -> Vec<Vec<String>> {
+        records(input, &CsvReadOptions::new())
+            .unwrap()
+            .into_iter()
+            .map(|(_, fields)| fields)
+            .collect()
+    }
+
+    #[test]
+    fn quoted_delimiter_stays_in_field() {
+        assert_eq!(fields("a,\"b,c\",d\n"), vec![vec!["a", "b,c", "d"]]);
+    }
+
+    #[test]
+    fn doubled_quote_is_literal_quote() {
+        assert_eq!(
+            fields("\"say \"\"hi\"\"\",x\n"),
+            vec![vec!["say \"hi\"", "x"]]
+        );
+    }
+
+    #[test]
+    fn crlf_inside_quotes_becomes_newline() {
+        let records = records("\"x\r\ny\",z\r\nnext\r\n", &CsvReadOptions::new()).unwrap();
+        assert_eq!(
+            records,
+            vec![
+                (1, vec!["x\ny".to_string(), "z".to_string()]),
+                (3, vec!["next".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated_quote_reports_starting_line() {
+        let err = records("a\nb,\"open\nmore\n", &CsvReadOptions::new()).unwrap_err();
+        match err {
+            DsError::CsvParse(e) => {
+                assert_eq!(e.line, 2);
+                assert_eq!(e.path, "test.csv");
+                assert_eq!(e.message, "unterminated quoted field");
+            }
+            other => panic!("unexpected error {other:?}"),
+        }
+    }
+
+    #[test]
+    fn comment_and_blank_lines_are_skipped() {
+        let options = CsvReadOptions::new().comment("#");
+        let records = records("a\n\n  \n# note, with comma\nb\n", &options).unwrap();
+        assert_eq!(
+            records,
+            vec![(1, vec!["a".to_string()]), (5, vec!["b".to_string()])]
+        );
+    }
+
+    #[test]
+    fn trailing_delimiter_gives_empty_field() {
+        assert_eq!(fields("a,b,\n"), vec![vec!["a", "b", ""]]);
+        assert_eq!(fields("a,\"\"\n"), vec![vec!["a", ""]]);
+    }
+
+    #[test]
+    fn sniffed_delimiter_splits_lines_evenly() {
+        let cases = [
+            ("sniff_semicolon", "a;b;c\n1;2,5;3\n4;5;6\n", ';'),
+            ("sniff_tab", "a\tb\n\"x;y\"\t1\n", '\t'),
+            ("sniff_pipe", "# a,b\na|b\n1|2\n", '|'),
+            ("sniff_single", "a\n1\n", ','),
+        ];
+        for (name, text, expected) in cases {
+            let path = temp_csv(name, text);
+            let options = CsvReadOptions::new().comment("#");
+            let delimiter = sniff_delimiter(&path, &options);
+            std::fs::remove_file(&path).unwrap();
+            assert_eq!(delimiter.unwrap(), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn sniff_sample_may_end_inside_a_character() {
+        // The first byte of `é` is the last byte of the sample
+        let cut = SNIFF_BYTES as usize - 1;
+        let mut text = String::from("a;b\n");
+        while text.len() + 8 < cut {
+            text.push_str("1;2\n");
+        }
+        text.push_str(&"1".repeat(cut - text.len() - 1));
+        text.push_str(";é\n");
+        assert_eq!(text.as_bytes()[cut], "é".as_bytes()[0]);
+
+        let path = temp_csv("sniff_boundary", &text);
+        let df = read_csv(&path, &CsvReadOptions::new().sniff_delimiter());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(df.unwrap().column_names(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn values_past_the_sample_widen_the_type() {
+        let path = temp_csv("widen", "i,f,s,e\n1,1,1,\n2,2.5,x,\n3,,true,\n");
+        let df = read_csv(&path, &CsvReadOptions::new().infer_rows(1)).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let types = df.column_types();
+        assert_eq!(types["i"], "Int64");
+        assert_eq!(types["f"], "Float64");
+        assert_eq!(types["s"], "Utf8");
+        // A column with no values is read as strings
+        assert_eq!(types["e"], "Utf8");
+        assert_eq!(
+            df.read_column("f").unwrap(),
+            Column::NullableFloat64(vec![Some(1.0), Some(2.5), None])
+        );
+    }
+
+    #[test]
+    fn lenient_reads_convert_typed_columns() {
+        let path = temp_csv("lenient_reads", "n,x,b\n1,1.5,true\n,2,false\n");
+        let df = read_csv(&path, &CsvReadOptions::new()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(df.read_column_string("n").unwrap(), vec!["1", ""]);
+        assert_eq!(
+            df.read_column_string_opt("x").unwrap(),
+            vec![Some("1.5".to_string()), Some("2.0".to_string())]
+        );
+        assert_eq!(df.read_column_f64("n").unwrap()[0], 1.0);
+        assert!(df.read_column_f64("n").unwrap()[1].is_nan());
+        // Decimals are not integers, so they read as 0
+        assert_eq!(df.read_column_i64("x").unwrap(), vec![0, 0]);
+        assert_eq!(df.read_column_i64("b").unwrap(), vec![0, 0]);
+    }
+
+    #[test]
+    fn strict_frames_reject_other_types() {
+        let path = temp_csv("strict_reads", "n,s\n1,a\n2,b\n");
+        let df = read_csv(&path, &CsvReadOptions::new().strict(true)).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(df.read_column_f64("n").unwrap(), vec![1.0, 2.0]);
+        for result in [
+            df.read_column_string("n").map(|_| ()),
+            df.read_column_i64("s").map(|_| ()),
+        ] {
+            assert!(matches!(result, Err(DsError::TypeMismatch { .. })));
+        }
+    }
+
+    #[test]
+    fn strict_read_fails_on_values_past_the_sample() {
+        let path = temp_csv("strict_sample", "a,b\n1,x\n2,y\nz,w\n");
+        let strict = CsvReadOptions::new().strict(true).infer_rows(2);
+        let error = read_csv(&path, &strict).err();
+        let unsampled = read_csv(&path, &strict.clone().infer_rows(0));
+        std::fs::remove_file(&path).unwrap();
+
+        match error {
+            Some(DsError::CsvParse(e)) => {
+                assert_eq!(e.line, 4);
+                assert_eq!(e.column.as_deref(), Some("a"));
+                assert_eq!(e.value.as_deref(), Some("z"));
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+        // Without a sample every column is Utf8, which any value fits
+        assert_eq!(unsampled.unwrap().column_types()["a"], "Utf8");
+    }
+
+    #[test]
+    fn report_lists_every_problem_by_line() {
+        let path = temp_csv(
+            "report",
+            "n,t\n1,2020-01-01\nx,2020-01-02\n3,soon,extra\n4\n",
+        );
+        let options = CsvReadOptions::new().infer_rows(1);
+        let (df, errors) = read_csv_report(&path, &options).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let found: Vec<(usize, Option<&str>, Option<&str>)> = errors
+            .iter()
+            .map(|e| (e.line, e.column.as_deref(), e.value.as_deref()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (3, Some("n"), Some("x")),
+                (4, None, Some("extra")),
+                (4, Some("t"), Some("soon")),
+                (5, Some("t"), None),
+            ]
+        );
+        // Bad values and missing fields are NULL
+        assert_eq!(
+            df.read_column("n").unwrap(),
+            Column::NullableInt64(vec![Some(1), None, Some(3), Some(4)])
+        );
+        assert_eq!(df.column_names(), vec!["n", "t"]);
+    }
+
+    #[test]
+    fn na_tokens_read_as_null() {
+        let path = temp_csv("na_tokens", "n,s,code\n1,a,-1\n NA ,NA,2\n3,,-1\n");
+        let options = CsvReadOptions::new()
+            .na_values(vec!["NA"])
+            .column_na_values("code", vec!["-1"]);
+        let df = read_csv(&path, &options).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // NA tokens do not stop a column from being inferred as numbers
+        assert_eq!(
+            df.read_column("n").unwrap(),
+            Column::NullableInt64(vec![Some(1), None, Some(3)])
+        );
+        assert_eq!(
+            df.read_column_string_opt("n").unwrap(),
+            vec![Some("1".to_string()), None, Some("3".to_string())]
+        );
+        // In string columns NA is NULL but an empty field stays empty
+        assert_eq!(
+            df.read_column("s").unwrap(),
+            Column::NullableUtf8(vec![Some("a".to_string()), None, Some(String::new())])
+        );
+        assert_eq!(
+            df.read_column("code").unwrap(),
+            Column::NullableInt64(vec![None, Some(2), None])
+        );
+    }
+
+    #[test]
+    fn listed_empty_token_makes_empty_strings_null() {
+        let path = temp_csv("na_empty", "s,t\na,-1\n,b\n");
+        let options = CsvReadOptions::new().na_values(vec![""]);
+        let df = read_csv(&path, &options).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            df.read_column_string_opt("s").unwrap(),
+            vec![Some("a".to_string()), None]
+        );
+        // A column without empty fields keeps its plain type
+        assert_eq!(
+            df.read_column("t").unwrap(),
+            Column::Utf8(vec!["-1".to_string(), "b".to_string()])
+        );
+    }
+}
@@ -1,17 +1,630 @@
 use std::collections::HashMap;
+use std::ops::Range;
+
+use arrow::datatypes::{DataType, TimeUnit};
+
+use crate::error::DsError;
+
+/// A column of values held in memory
+#[derive(Debug, Clone, PartialEq)]
+pub enum Column {
+    Int64(Vec<i64>),
+    Float64(Vec<f64>),
+    Utf8(Vec<String>),
+    Boolean(Vec<bool>),
+    /// UTC timestamps counted in the given unit since the epoch
+    Timestamp(TimeUnit, Vec<i64>),
+    NullableInt64(Vec<Option<i64>>),
+    NullableFloat64(Vec<Option<f64>>),
+    NullableUtf8(Vec<Option<String>>),
+    NullableBoolean(Vec<Option<bool>>),
+    NullableTimestamp(TimeUnit, Vec<Option<i64>>),
+}
+
+impl Column {
+    pub fn len(&self) -> usize {
+        match self {
+            Column::Int64(v) => v.len(),
+            Column::Float64(v) => v.len(),
+            Column::Utf8(v) => v.len(),
+            Column::Boolean(v) => v.len(),
+            Column::Timestamp(_, v) => v.len(),
+            Column::NullableInt64(v) => v.len(),
+            Column::NullableFloat64(v) => v.len(),
+            Column::NullableUtf8(v) => v.len(),
+            Column::NullableBoolean(v) => v.len(),
+            Column::NullableTimestamp(_, v) => v.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        matches!(
+            self,
+            Column::NullableInt64(_)
+                | Column::NullableFloat64(_)
+                | Column::NullableUtf8(_)
+                | Column::NullableBoolean(_)
+                | Column::NullableTimestamp(..)
+        )
+    }
+
+    /// Arrow type of the column; its Debug form is what `DataFrame::column_types` reports
+    pub fn data_type(&self) -> DataType {
+        match self {
+            Column::Int64(_) | Column::NullableInt64(_) => DataType::Int64,
+            Column::Float64(_) | Column::NullableFloat64(_) => DataType::Float64,
+            Column::Utf8(_) | Column::NullableUtf8(_) => DataType::Utf8,
+            Column::Boolean(_) | Column::NullableBoolean(_) => DataType::Boolean,
+            Column::Timestamp(unit, _) | Column::NullableTimestamp(unit, _) => {
+                DataType::Timestamp(*unit, Some("UTC".into()))
+            }
+        }
+    }
+
+    /// The nullable variant holding the same values
+    pub fn into_nullable(self) -> Column {
+        match self {
+            Column::Int64(v) => Column::NullableInt64(v.into_iter().map(Some).collect()),
+            Column::Float64(v) => Column::NullableFloat64(v.into_iter().map(Some).collect()),
+            Column::Utf8(v) => Column::NullableUtf8(v.into_iter().map(Some).collect()),
+            Column::Boolean(v) => Column::NullableBoolean(v.into_iter().map(Some).collect()),
+            Column::Timestamp(unit, v) => {
+                Column::NullableTimestamp(unit, v.into_iter().map(Some).collect())
+            }
+            other => other,
+        }
+    }
+
+    /// The values at `rows`, in that order
+    pub(crate) fn take(&self, rows: &[usize]) -> Column {
+        match self {
+            Column::Int64(v) => Column::Int64(rows.iter().map(|&i| v[i]).collect()),
+            Column::Float64(v) => Column::Float64(rows.iter().map(|&i| v[i]).collect()),
+            Column::Utf8(v) => Column::Utf8(rows.iter().map(|&i| v[i].clone()).collect()),
+            Column::Boolean(v) => Column::Boolean(rows.iter().map(|&i| v[i]).collect()),
+            Column::Timestamp(unit, v) => {
+                Column::Timestamp(*unit, rows.iter().map(|&i| v[i]).collect())
+            }
+            Column::NullableInt64(v) => Column::NullableInt64(rows.iter().map(|&i| v[i]).collect()),
+            Column::NullableFloat64(v) => {
+                Column::NullableFloat64(rows.iter().map(|&i| v[i]).collect())
+            }
+            Column::NullableUtf8(v) => {
+                Column::NullableUtf8(rows.iter().map(|&i| v[i].clone()).collect())
+            }
+            Column::NullableBoolean(v) => {
+                Column::NullableBoolean(rows.iter().map(|&i| v[i]).collect())
+            }
+            Column::NullableTimestamp(unit, v) => {
+                Column::NullableTimestamp(*unit, rows.iter().map(|&i| v[i]).collect())
+            }
+        }
+    }
+
+    /// Join columns end to end; mixing plain and nullable variants of one type
+    /// gives the nullable variant
+    pub(crate) fn concat(parts: Vec<Column>) -> Result<Column, DsError> {
+        let nullable = parts.iter().any(Column::is_nullable);
+        let mut parts = parts
+            .into_iter()
+            .map(|col| if nullable { col.into_nullable() } else { col });
+        let Some(mut out) = parts.next() else {
+            return Err(DsError::InvalidArgument(
+                "no columns to concatenate".to_string(),
+            ));
+        };
+        for col in parts {
+            match (&mut out, col) {
+                (Column::Int64(a), Column::Int64(b)) => a.extend(b),
+                (Column::Float64(a), Column::Float64(b)) => a.extend(b),
+                (Column::Utf8(a), Column::Utf8(b)) => a.extend(b),
+                (Column::Boolean(a), Column::Boolean(b)) => a.extend(b),
+                (Column::Timestamp(ua, a), Column::Timestamp(ub, b)) if *ua == ub => a.extend(b),
+                (Column::NullableInt64(a), Column::NullableInt64(b)) => a.extend(b),
+                (Column::NullableFloat64(a), Column::NullableFloat64(b)) => a.extend(b),
+                (Column::NullableUtf8(a), Column::NullableUtf8(b)) => a.extend(b),
+                (Column::NullableBoolean(a), Column::NullableBoolean(b)) => a.extend(b),
+                (Column::NullableTimestamp(ua, a), Column::NullableTimestamp(ub, b))
+                    if *ua == ub =>
+                {
+                    a.extend(b)
+                }
+                (a, b) => {
+                    return Err(DsError::InvalidArgument(format!(
+                        "cannot concatenate {:?} and {:?} columns",
+                        a.data_type(),
+                        b.data_type()
+                    )));
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// A single value of a column
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum Scalar {
+    Int64(i64),
+    Float64(f64),
+    Utf8(String),
+    Boolean(bool),
+    /// UTC timestamp counted in the given unit since the epoch
+    Timestamp(TimeUnit, i64),
+}
+
+/// Count of non-NULL values, NULL count, min and max of a column. NaN counts
+/// as a value but is ignored by min and max, as in Parquet statistics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnSummary {
+    pub count: usize,
+    pub null_count: usize,
+    /// None when the column has no non-NULL, non-NaN values
+    pub min: Option<Scalar>,
+    pub max: Option<Scalar>,
+}
+
+impl ColumnSummary {
+    /// Summary of `rows` NULL values
+    pub(crate) fn all_null(rows: usize) -> ColumnSummary {
+        ColumnSummary {
+            count: 0,
+            null_count: rows,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Summarise a column by scanning its values
+    pub(crate) fn scan(column: &Column) -> ColumnSummary {
+        fn summary<T: Clone + PartialOrd>(
+            values: impl Iterator<Item = Option<T>>,
+            is_nan: impl Fn(&T) -> bool,
+            scalar: impl Fn(T) -> Scalar,
+        ) -> ColumnSummary {
+            let (mut count, mut null_count) = (0, 0);
+            let mut min_max: Option<(T, T)> = None;
+            for value in values {
+                let Some(value) = value else {
+                    null_count += 1;
+                    continue;
+                };
+                count += 1;
+                if is_nan(&value) {
+                    continue;
+                }
+                min_max = Some(match min_max {
+                    None => (value.clone(), value),
+                    Some((min, max)) if value < min => (value, max),
+                    Some((min, max)) if value > max => (min, value),
+                    Some(min_max) => min_max,
+                });
+            }
+            let (min, max) = match min_max {
+                Some((min, max)) => (Some(scalar(min)), Some(scalar(max))),
+                None => (None, None),
+            };
+            ColumnSummary {
+                count,
+                null_count,
+                min,
+                max,
+            }
+        }
+
+        fn never_nan<T>(_: &T) -> bool {
+            false
+        }
+        match column {
+            Column::Int64(v) => summary(v.iter().copied().map(Some), never_nan, Scalar::Int64),
+            Column::NullableInt64(v) => summary(v.iter().copied(), never_nan, Scalar::Int64),
+            Column::Float64(v) => summary(
+                v.iter().copied().map(Some),
+                |x: &f64| x.is_nan(),
+                Scalar::Float64,
+            ),
+            Column::NullableFloat64(v) => {
+                summary(v.iter().copied(), |x: &f64| x.is_nan(), Scalar::Float64)
+            }
+            Column::Utf8(v) => summary(v.iter().cloned().map(Some), never_nan, Scalar::Utf8),
+            Column::NullableUtf8(v) => summary(v.iter().cloned(), never_nan, Scalar::Utf8),
+            Column::Boolean(v) => summary(v.iter().copied().map(Some), never_nan, Scalar::Boolean),
+            Column::NullableBoolean(v) => summary(v.iter().copied(), never_nan, Scalar::Boolean),
+            Column::Timestamp(unit, v) => summary(v.iter().copied().map(Some), never_nan, |t| {
+                Scalar::Timestamp(*unit, t)
+            }),
+            Column::NullableTimestamp(unit, v) => summary(v.iter().copied(), never_nan, |t| {
+                Scalar::Timestamp(*unit, t)
+            }),
+        }
+    }
+
+    /// Combine the summaries of two runs of rows of one column
+    pub(crate) fn merge(self, other: ColumnSummary) -> ColumnSummary {
+        let pick =
+            |a: Option<Scalar>, b: Option<Scalar>, take_b: fn(&Scalar, &Scalar) -> bool| match (
+                a, b,
+            ) {
+                (Some(a), Some(b)) => Some(if take_b(&a, &b) { b } else { a }),
+                (a, b) => a.or(b),
+            };
+        ColumnSummary {
+            count: self.count + other.count,
+            null_count: self.null_count + other.null_count,
+            min: pick(self.min, other.min, |a, b| b < a),
+            max: pick(self.max, other.max, |a, b| b > a),
+        }
+    }
+}
 
 pub trait DataFrame: Send + Sync {
     fn shape(&self) -> (usize, usize);
     fn column_names(&self) -> Vec<String>;
     fn column_types(&self) -> HashMap<String, String>;
 
-    fn read_column_string(&self, column_name: &str) -> Vec<String>;
-    fn read_column_i64(&self, column_name: &str) -> Vec<i64>;
+    /// Whether the frame has a time index readable by `read_index_microsecond`
+    /// that is not listed in `column_names`
+    fn has_index(&self) -> bool {
+        false
+    }
+
+    /// Names of the index levels, outermost first; unnamed levels are called
+    /// `__index_level_N__`. Index levels are not listed in `column_names`.
+    fn index_names(&self) -> Vec<String> {
+        if self.has_index() {
+            vec!["__index_level_0__".to_string()]
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Index levels stored as columns, as `(field, level name)` pairs outermost
+    /// first; each field is readable with `read_column`. Empty when the index
+    /// is not stored as columns, e.g. a RangeIndex or a `MemDataFrame` index.
+    fn index_fields(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    /// Read a column in its native type. The defaults of `read_column_timestamp`,
+    /// `read_column_bool` and `read_column_i64_opt` are built on it.
+    fn read_column(&self, column_name: &str) -> Result<Column, DsError>;
+
+    /// Read a string column into Vec<String>, failing on NULL
+    fn read_column_string(&self, column_name: &str) -> Result<Vec<String>, DsError>;
+    /// Read a string column into Vec<Option<String>>, keeping NULL as None
+    fn read_column_string_opt(&self, column_name: &str) -> Result<Vec<Option<String>>, DsError> {
+        Ok(self
+            .read_column_string(column_name)?
+            .into_iter()
+            .map(Some)
+            .collect())
+    }
+    /// Read an Int64 column into Vec<i64>, replacing NULL with 0
+    fn read_column_i64(&self, column_name: &str) -> Result<Vec<i64>, DsError>;
+    /// Read an Int64 column into Vec<Option<i64>>, keeping NULL as None
+    fn read_column_i64_opt(&self, column_name: &str) -> Result<Vec<Option<i64>>, DsError> {
+        match self.read_column(column_name)? {
+            Column::Int64(v) => Ok(v.into_iter().map(Some).collect()),
+            Column::NullableInt64(v) => Ok(v),
+            other => Err(DsError::TypeMismatch {
+                column: column_name.to_string(),
+                expected: "Int64".to_string(),
+                found: format!("{:?}", other.data_type()),
+            }),
+        }
+    }
     /// Read a Float64 column into Vec<f64>, replacing NULL with NaN
-    fn read_column_f64(&self, column_name: &str) -> Vec<f64>;
+    fn read_column_f64(&self, column_name: &str) -> Result<Vec<f64>, DsError>;
+    /// Read a Boolean column into Vec<bool>, failing on NULL
+    fn read_column_bool(&self, column_name: &str) -> Result<Vec<bool>, DsError> {
+        let values = match self.read_column(column_name)? {
+            Column::Boolean(v) => return Ok(v),
+            Column::NullableBoolean(v) => v,
+            other => {
+                return Err(DsError::TypeMismatch {
+                    column: column_name.to_string(),
+                    expected: "Boolean".to_string(),
+                    found: format!("{:?}", other.data_type()),
+                });
+            }
+        };
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(row, opt)| {
+                opt.ok_or_else(|| DsError::NullInNonNullable {
+                    column: column_name.to_string(),
+                    row,
+                })
+            })
+            .collect()
+    }
+    /// Read several Float64 columns, returned in the order of `column_names`
+    fn read_columns_f64(&self, column_names: &[&str]) -> Result<Vec<Vec<f64>>, DsError> {
+        column_names
+            .iter()
+            .map(|name| self.read_column_f64(name))
+            .collect()
+    }
+
+    /// Read a temporal column as counts of `unit` since the epoch, failing on NULL.
+    /// Timestamps of any unit, Date32 and Date64 are converted, rounding down;
+    /// Int64 columns are taken to count `unit` already. Timestamps without a
+    /// timezone are taken as UTC.
+    fn read_column_timestamp(
+        &self,
+        column_name: &str,
+        unit: TimeUnit,
+    ) -> Result<Vec<i64>, DsError> {
+        self.read_column_timestamp_range(column_name, unit, 0..self.shape().0)
+    }
+
+    /// Read the index as microseconds since the epoch
+    fn read_index_microsecond(&self) -> Result<Vec<i64>, DsError>;
+    /// Read the column "timestamp" as seconds since the epoch
+    fn read_timestamp_second(&self) -> Result<Vec<i64>, DsError> {
+        self.read_column_timestamp("timestamp", TimeUnit::Second)
+    }
+
+    /// Count, NULL count, min and max of a column, from file metadata where
+    /// the backend has complete statistics and by scanning the column otherwise
+    fn column_summary(&self, column_name: &str) -> Result<ColumnSummary, DsError> {
+        Ok(ColumnSummary::scan(&self.read_column(column_name)?))
+    }
+    /// Min and max of a column, None when it holds only NULL or NaN; see `column_summary`
+    fn column_min_max(&self, column_name: &str) -> Result<Option<(Scalar, Scalar)>, DsError> {
+        let summary = self.column_summary(column_name)?;
+        Ok(summary.min.zip(summary.max))
+    }
+
+    /// Read rows `rows` of a string column, failing on NULL
+    fn read_column_string_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<String>, DsError> {
+        check_row_range(&rows, self.shape().0)?;
+        Ok(self.read_column_string(column_name)?[rows].to_vec())
+    }
+    /// Read rows `rows` of a string column, keeping NULL as None
+    fn read_column_string_opt_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<Option<String>>, DsError> {
+        check_row_range(&rows, self.shape().0)?;
+        Ok(self.read_column_string_opt(column_name)?[rows].to_vec())
+    }
+    /// Read rows `rows` of an Int64 column, replacing NULL with 0
+    fn read_column_i64_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<i64>, DsError> {
+        check_row_range(&rows, self.shape().0)?;
+        Ok(self.read_column_i64(column_name)?[rows].to_vec())
+    }
+    /// Read rows `rows` of a Float64 column, replacing NULL with NaN
+    fn read_column_f64_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<f64>, DsError> {
+        check_row_range(&rows, self.shape().0)?;
+        Ok(self.read_column_f64(column_name)?[rows].to_vec())
+    }
+    /// Read rows `rows` of a temporal column, see `read_column_timestamp`
+    fn read_column_timestamp_range(
+        &self,
+        column_name: &str,
+        unit: TimeUnit,
+        rows: Range<usize>,
+    ) -> Result<Vec<i64>, DsError> {
+        check_row_range(&rows, self.shape().0)?;
+        let start = rows.start;
+        let (from, values): (TimeUnit, Vec<Option<i64>>) = match self.read_column(column_name)? {
+            Column::Timestamp(from, v) => (from, v[rows].iter().copied().map(Some).collect()),
+            Column::NullableTimestamp(from, v) => (from, v[rows].to_vec()),
+            Column::Int64(v) => (unit, v[rows].iter().copied().map(Some).collect()),
+            Column::NullableInt64(v) => (unit, v[rows].to_vec()),
+            other => {
+                return Err(DsError::TypeMismatch {
+                    column: column_name.to_string(),
+                    expected: "Timestamp".to_string(),
+                    found: format!("{:?}", other.data_type()),
+                });
+            }
+        };
+        convert_timestamps(column_name, start, values, from, unit)
+    }
+    /// Read rows `rows` of the index, see `read_index_microsecond`
+    fn read_index_microsecond_range(&self, rows: Range<usize>) -> Result<Vec<i64>, DsError> {
+        check_row_range(&rows, self.shape().0)?;
+        Ok(self.read_index_microsecond()?[rows].to_vec())
+    }
+
+    /// Read the index (microseconds) and columns of any type for the rows
+    /// whose index lies in the window `[start, end)`, given in microseconds
+    fn read_window(
+        &self,
+        start: i64,
+        end: i64,
+        column_names: &[&str],
+    ) -> Result<(Vec<i64>, Vec<Column>), DsError> {
+        let index = self.read_index_microsecond()?;
+        let rows: Vec<usize> = (0..index.len())
+            .filter(|&i| start <= index[i] && index[i] < end)
+            .collect();
+        let columns = column_names
+            .iter()
+            .map(|name| Ok(self.read_column(name)?.take(&rows)))
+            .collect::<Result<_, DsError>>()?;
+        Ok((rows.iter().map(|&i| index[i]).collect(), columns))
+    }
+
+    /// Read the index (microseconds) and Float64 columns for the rows whose
+    /// index lies in the window `[start, end)`, given in microseconds
+    fn read_window_f64(
+        &self,
+        start: i64,
+        end: i64,
+        column_names: &[&str],
+    ) -> Result<(Vec<i64>, Vec<Vec<f64>>), DsError> {
+        let index = self.read_index_microsecond()?;
+        let rows: Vec<usize> = (0..index.len())
+            .filter(|&i| start <= index[i] && index[i] < end)
+            .collect();
+        let columns = self
+            .read_columns_f64(column_names)?
+            .into_iter()
+            .map(|col| rows.iter().map(|&i| col[i]).collect())
+            .collect();
+        Ok((rows.iter().map(|&i| index[i]).collect(), columns))
+    }
+}
+
+/// Check that `rows` is a valid range within `row_count` rows
+pub(crate) fn check_row_range(rows: &Range<usize>, row_count: usize) -> Result<(), DsError> {
+    if rows.start > rows.end || rows.end > row_count {
+        return Err(DsError::InvalidArgument(format!(
+            "row range {:?} out of bounds for {} rows",
+            rows, row_count
+        )));
+    }
+    Ok(())
+}
+
+/// Convert counts of `from` units since the epoch to `to` units, rounding down.
+/// `first_row` is the row of the first value, used in errors for NULL and overflow.
+pub(crate) fn convert_timestamps(
+    column_name: &str,
+    first_row: usize,
+    values: impl IntoIterator<Item = Option<i64>>,
+    from: TimeUnit,
+    to: TimeUnit,
+) -> Result<Vec<i64>, DsError> {
+    let per_second = |unit: TimeUnit| -> i64 {
+        match unit {
+            TimeUnit::Second => 1,
+            TimeUnit::Millisecond => 1_000,
+            TimeUnit::Microsecond => 1_000_000,
+            TimeUnit::Nanosecond => 1_000_000_000,
+        }
+    };
+    let (from_scale, to_scale) = (per_second(from), per_second(to));
+
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(k, opt)| {
+            let row = first_row + k;
+            let value = opt.ok_or_else(|| DsError::NullInNonNullable {
+                column: column_name.to_string(),
+                row,
+            })?;
+            if to_scale >= from_scale {
+                value.checked_mul(to_scale / from_scale).ok_or_else(|| {
+                    DsError::InvalidArgument(format!(
+                        "value {} at row {} of column '{}' overflows converting {:?} to {:?}",
+                        value, row, column_name, from, to
+                    ))
+                })
+            } else {
+                Ok(value.div_euclid(from_scale / to_scale))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::MemDataFrame;
+
+    fn frame(column: Column) -> MemDataFrame {
+        MemDataFrame::builder()
+            .add_column("t", column)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn timestamps_convert_between_units() {
+        let nanos = frame(Column::Timestamp(
+            TimeUnit::Nanosecond,
+            vec![1_500_000_000, -1, 0],
+        ));
+        assert_eq!(
+            nanos.read_column_timestamp("t", TimeUnit::Second).unwrap(),
+            vec![1, -1, 0]
+        );
+        assert_eq!(
+            nanos
+                .read_column_timestamp("t", TimeUnit::Millisecond)
+                .unwrap(),
+            vec![1_500, -1, 0]
+        );
+
+        let seconds = frame(Column::Timestamp(TimeUnit::Second, vec![2, -3]));
+        assert_eq!(
+            seconds
+                .read_column_timestamp("t", TimeUnit::Nanosecond)
+                .unwrap(),
+            vec![2_000_000_000, -3_000_000_000]
+        );
+        assert_eq!(
+            seconds
+                .read_column_timestamp_range("t", TimeUnit::Millisecond, 1..2)
+                .unwrap(),
+            vec![-3_000]
+        );
+    }
+
+    #[test]
+    fn int64_counts_the_requested_unit() {
+        let df = frame(Column::Int64(vec![7, 8]));
+        assert_eq!(
+            df.read_column_timestamp("t", TimeUnit::Millisecond)
+                .unwrap(),
+            vec![7, 8]
+        );
+    }
+
+    #[test]
+    fn timestamp_errors_name_the_row() {
+        let overflow = frame(Column::Timestamp(TimeUnit::Second, vec![0, i64::MAX / 10]));
+        let error = overflow
+            .read_column_timestamp("t", TimeUnit::Nanosecond)
+            .unwrap_err();
+        assert!(
+            matches!(&error, DsError::InvalidArgument(m) if m.contains("row 1")),
+            "{:?}",
+            error
+        );
+
+        let nulls = frame(Column::NullableTimestamp(
+            TimeUnit::Second,
+            vec![Some(1), Some(2), None],
+        ));
+        assert!(matches!(
+            nulls.read_column_timestamp_range("t", TimeUnit::Second, 1..3),
+            Err(DsError::NullInNonNullable { row: 2, .. })
+        ));
+        assert_eq!(
+            nulls
+                .read_column_timestamp_range("t", TimeUnit::Second, 0..2)
+                .unwrap(),
+            vec![1, 2]
+        );
 
-    /// Read the first column (index) of type Timestamp(Microsecond, Some("UTC")) into Vec<i64>
-    fn read_index_microsecond(&self) -> Vec<i64>;
-    /// Read the column "timestamp" of type Timestamp(Second, Some("UTC")) into Vec<i64>
-    fn read_timestamp_second(&self) -> Vec<i64>;
+        let floats = frame(Column::Float64(vec![1.0]));
+        assert!(matches!(
+            floats.read_column_timestamp("t", TimeUnit::Second),
+            Err(DsError::TypeMismatch { .. })
+        ));
+    }
 }
@@ -0,0 +1,1430 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use arrow::array::AsArray;
+use arrow::compute::cast;
+use arrow::compute::kernels::temporal::{DatePart, date_part};
+use arrow::datatypes::{DataType, Int32Type, TimeUnit};
+
+use crate::csv::{CsvReadOptions, read_csv_frames};
+use crate::dataframe::{Column, ColumnSummary, DataFrame, check_row_range, convert_timestamps};
+use crate::error::DsError;
+use crate::parquet::{
+    DEFAULT_PART_NAME, INDEX_NAME, ParquetWriteOptions, append_dataset_file, column_array,
+    dataframe_columns, parquet_files_in, read_parquet, written_index,
+};
+
+/// Hive's marker for a NULL partition value
+const HIVE_NULL: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Partition `(key, value)` pairs of a file, outermost directory first
+type Partitions = Vec<(String, Option<String>)>;
+
+/// Read a Hive-partitioned dataset such as `symbol=XYZ/date=2026-01-01/part-0.parquet`.
+///
+/// Partition keys are exposed as columns after the file columns, typed Int64
+/// when every value parses as an integer and Utf8 otherwise. `filters` are
+/// `(key, value)` pairs: a file is opened only if, for every key named in
+/// `filters`, its partition value equals one of the values given for that key.
+/// File schemas are unified by column name; rows from files lacking a column
+/// read as NULL.
+pub fn read_parquet_dataset(
+    dir: &str,
+    filters: &[(&str, &str)],
+) -> Result<Box<dyn DataFrame>, DsError> {
+    let mut files = Vec::new();
+    collect_files(Path::new(dir), &[], &mut files)?;
+
+    let mut parts = Vec::new();
+    for (path, partitions) in files {
+        if !matches_filters(&partitions, filters) {
+            continue;
+        }
+        parts.push(Part::open(&path.to_string_lossy(), partitions)?);
+    }
+
+    Ok(Box::new(MultiFileDataFrame::new(parts, false)?))
+}
+
+/// Read Parquet files with identical schemas as one frame whose rows are
+/// those of each file in turn. Only the footers are read here, so `shape`
+/// costs no decoding.
+pub fn read_parquet_many(paths: &[&str]) -> Result<Box<dyn DataFrame>, DsError> {
+    let parts = paths
+        .iter()
+        .map(|path| Part::open(path, Vec::new()))
+        .collect::<Result<_, _>>()?;
+    Ok(Box::new(MultiFileDataFrame::new(parts, true)?))
+}
+
+/// Read the Parquet files matching `pattern`, e.g. `data/data_2025_*.parquet`,
+/// in natural sort order as with `read_parquet_many`. `*` and `?` may appear
+/// in any path component.
+pub fn read_parquet_glob(pattern: &str) -> Result<Box<dyn DataFrame>, DsError> {
+    let paths = expand_glob(pattern)?;
+    if paths.is_empty() {
+        return Err(DsError::InvalidArgument(format!(
+            "no files match '{}'",
+            pattern
+        )));
+    }
+    read_parquet_many(&paths.iter().map(String::as_str).collect::<Vec<_>>())
+}
+
+/// Read CSV files with identical columns as one frame, see `read_csv`. Column
+/// types are inferred from all the files, so a column of integers in one file
+/// and decimals in another reads as Float64.
+pub fn read_csv_many(
+    paths: &[&str],
+    options: &CsvReadOptions,
+) -> Result<Box<dyn DataFrame>, DsError> {
+    let parts = paths
+        .iter()
+        .zip(read_csv_frames(paths, options)?)
+        .map(|(path, frame)| Part::new(path, frame, Vec::new()))
+        .collect();
+    Ok(Box::new(MultiFileDataFrame::new(parts, true)?))
+}
+
+/// Paths of the files matching `pattern`, in natural sort order
+fn expand_glob(pattern: &str) -> Result<Vec<String>, DsError> {
+    let mut paths = vec![PathBuf::new()];
+    for component in Path::new(pattern).components() {
+        let component = component.as_os_str().to_string_lossy();
+        if !component.contains(['*', '?']) {
+            paths = paths.into_iter().map(|p| p.join(&*component)).collect();
+            continue;
+        }
+        let mut matched = Vec::new();
+        for dir in paths {
+            let listing = if dir.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                dir.as_path()
+            };
+            let Ok(entries) = std::fs::read_dir(listing) else {
+                continue;
+            };
+            for entry in entries {
+                let name = entry?.file_name().to_string_lossy().to_string();
+                if wildcard_match(&component, &name) {
+                    matched.push(dir.join(name));
+                }
+            }
+        }
+        paths = matched;
+    }
+
+    let mut files: Vec<String> = paths
+        .into_iter()
+        .filter(|p| p.is_file())
+        .map(|p| p.to_string_lossy().to_string())
+        .collect();
+    alphanumeric_sort::sort_str_slice(&mut files);
+    Ok(files)
+}
+
+/// Match `name` against a pattern where `*` matches any run of characters
+/// and `?` any single character
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` and the name position it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, tried)) = backtrack {
+            backtrack = Some((star, tried + 1));
+            p = star + 1;
+            n = tried + 1;
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Walk `dir` recursively, collecting Parquet files with the partition values
+/// parsed from `key=value` directory names. Names starting with `.` or `_`
+/// (e.g. `_SUCCESS`) are skipped.
+fn collect_files(
+    dir: &Path,
+    partitions: &[(String, Option<String>)],
+    files: &mut Vec<(PathBuf, Partitions)>,
+) -> Result<(), DsError> {
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+    entries.sort();
+
+    for path in entries {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if name.starts_with('.') || name.starts_with('_') {
+            continue;
+        }
+        if path.is_dir() {
+            let mut nested = partitions.to_vec();
+            if let Some((key, value)) = name.split_once('=') {
+                let value = percent_decode(value);
+                nested.push((key.to_string(), (value != HIVE_NULL).then_some(value)));
+            }
+            collect_files(&path, &nested, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "parquet") {
+            files.push((path, partitions.to_vec()));
+        }
+    }
+    Ok(())
+}
+
+/// Decode `%XX` escapes that Hive writers use for special characters
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if bytes[i] == b'%'
+            && let Some(b) = escaped
+        {
+            out.push(b);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+fn matches_filters(partitions: &[(String, Option<String>)], filters: &[(&str, &str)]) -> bool {
+    filters.iter().all(|(key, _)| {
+        let value = partitions
+            .iter()
+            .find(|(k, _)| k == key)
+            .and_then(|(_, v)| v.as_deref());
+        filters.iter().any(|(k, v)| k == key && Some(*v) == value)
+    })
+}
+
+/// A key `write_parquet_partitioned` splits rows by
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartitionKey {
+    /// The values of an Int64 or Utf8 column, which is left out of the files
+    Column(String),
+    /// Calendar year (UTC) of a timestamp column, written as `year=`
+    Year(String),
+    /// Calendar month 1-12 (UTC) of a timestamp column, written as `month=`
+    Month(String),
+    /// Calendar date (UTC) of a timestamp column, written as `date=YYYY-MM-DD`
+    Date(String),
+}
+
+/// What `write_parquet_partitioned` does with partitions that already hold files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WriteMode {
+    /// Fail before writing anything
+    #[default]
+    ErrorIfExists,
+    /// Replace the files of the partitions being written; other partitions are kept
+    Overwrite,
+    /// Add a new file next to the existing ones, which must have the same schema
+    Append,
+}
+
+/// Options for `write_parquet_partitioned`
+#[derive(Debug, Clone)]
+pub struct PartitionWriteOptions {
+    /// File name inside each partition directory; `{i}` is replaced by the
+    /// first free zero-padded counter
+    pub file_name: String,
+    pub mode: WriteMode,
+    /// Options for each file written
+    pub parquet: ParquetWriteOptions,
+}
+
+impl Default for PartitionWriteOptions {
+    fn default() -> Self {
+        Self {
+            file_name: DEFAULT_PART_NAME.to_string(),
+            mode: WriteMode::default(),
+            parquet: ParquetWriteOptions::default(),
+        }
+    }
+}
+
+/// Write `df` as a Hive-partitioned dataset under `dir`, one file per distinct
+/// combination of the `partition_by` values, e.g. `symbol=XYZ/year=2026/part-00000.parquet`.
+///
+/// Timestamp sources of derived keys may name the frame index as
+/// `__index_level_0__`. NULL and empty values go to `__HIVE_DEFAULT_PARTITION__`.
+/// With `WriteMode::Overwrite`, old files are removed only once every
+/// partition has been written. Returns the paths of the files written.
+pub fn write_parquet_partitioned(
+    dir: &str,
+    df: &dyn DataFrame,
+    partition_by: &[PartitionKey],
+    options: &PartitionWriteOptions,
+) -> Result<Vec<String>, DsError> {
+    if partition_by.is_empty() {
+        return Err(DsError::InvalidArgument(
+            "no partition keys given".to_string(),
+        ));
+    }
+    let mut keys: Vec<(String, Vec<Option<String>>)> = Vec::new();
+    for key in partition_by {
+        let (name, values) = partition_values(df, key)?;
+        if keys.iter().any(|(n, _)| *n == name) {
+            return Err(DsError::InvalidArgument(format!(
+                "duplicate partition key '{}'",
+                name
+            )));
+        }
+        keys.push((name, values));
+    }
+
+    // Rows of each partition, partitions in order of first appearance, keyed
+    // by the partition's path relative to `dir`
+    let mut groups: Vec<(PathBuf, Vec<usize>)> = Vec::new();
+    let mut group_of: HashMap<PathBuf, usize> = HashMap::new();
+    for row in 0..df.shape().0 {
+        let mut path = PathBuf::new();
+        for (name, values) in &keys {
+            let value = match values[row].as_deref() {
+                None | Some("") => HIVE_NULL.to_string(),
+                Some(v) => percent_encode(v),
+            };
+            path.push(format!("{}={}", name, value));
+        }
+        let group = *group_of.entry(path.clone()).or_insert_with(|| {
+            groups.push((path, Vec::new()));
+            groups.len() - 1
+        });
+        groups[group].1.push(row);
+    }
+
+    let root = Path::new(dir);
+    if options.mode == WriteMode::ErrorIfExists {
+        for (path, _) in &groups {
+            let path = root.join(path);
+            if path.is_dir() && !parquet_files_in(&path)?.is_empty() {
+                return Err(DsError::InvalidArgument(format!(
+                    "partition '{}' already contains data",
+                    path.display()
+                )));
+            }
+        }
+    }
+
+    // Overwritten partitions are first written under a staging directory,
+    // which readers skip, so the old files survive any failed write
+    let staging = (options.mode == WriteMode::Overwrite)
+        .then(|| root.join(format!("_staging-{}", std::process::id())));
+    if let Some(staging) = &staging {
+        let _ = std::fs::remove_dir_all(staging);
+    }
+    let result = write_partitions(
+        staging.as_deref().unwrap_or(root),
+        df,
+        partition_by,
+        &groups,
+        options,
+    );
+    let Some(staging) = staging else {
+        return result;
+    };
+    let replaced = result.and_then(|staged| {
+        let mut written = Vec::with_capacity(staged.len());
+        for ((path, _), staged) in groups.iter().zip(staged) {
+            let path = root.join(path);
+            let old = if path.is_dir() {
+                parquet_files_in(&path)?
+            } else {
+                Vec::new()
+            };
+            let staged = PathBuf::from(staged);
+            let file = path.join(staged.file_name().unwrap_or_default());
+            std::fs::create_dir_all(&path)?;
+            std::fs::rename(&staged, &file)?;
+            for old_file in old.into_iter().filter(|f| *f != file) {
+                std::fs::remove_file(old_file)?;
+            }
+            written.push(file.to_string_lossy().to_string());
+        }
+        Ok(written)
+    });
+    let _ = std::fs::remove_dir_all(&staging);
+    replaced
+}
+
+/// Write the rows of each `(relative path, rows)` group as a new file in that
+/// partition directory under `root`, returning the paths written
+fn write_partitions(
+    root: &Path,
+    df: &dyn DataFrame,
+    partition_by: &[PartitionKey],
+    groups: &[(PathBuf, Vec<usize>)],
+    options: &PartitionWriteOptions,
+) -> Result<Vec<String>, DsError> {
+    let columns: Vec<(String, Column)> = dataframe_columns(df, &options.parquet)?
+        .into_iter()
+        .filter(|(name, _)| !partition_by.contains(&PartitionKey::Column(name.clone())))
+        .collect();
+    let index = written_index(df, &options.parquet);
+    let mut written = Vec::with_capacity(groups.len());
+    for (path, rows) in groups {
+        let part_columns = columns
+            .iter()
+            .map(|(name, col)| (name.clone(), col.take(rows)))
+            .collect();
+        written.push(append_dataset_file(
+            &root.join(path),
+            &options.file_name,
+            part_columns,
+            &index,
+            &options.parquet,
+        )?);
+    }
+    Ok(written)
+}
+
+/// Directory key and per-row values of a partition key
+fn partition_values(
+    df: &dyn DataFrame,
+    key: &PartitionKey,
+) -> Result<(String, Vec<Option<String>>), DsError> {
+    let (name, parts) = match key {
+        PartitionKey::Column(name) => {
+            let values = match df.read_column(name)? {
+                Column::Int64(v) => v.iter().map(|x| Some(x.to_string())).collect(),
+                Column::Utf8(v) => v.into_iter().map(Some).collect(),
+                Column::Boolean(v) => v.iter().map(|x| Some(x.to_string())).collect(),
+                Column::NullableInt64(v) => v.iter().map(|x| x.map(|x| x.to_string())).collect(),
+                Column::NullableUtf8(v) => v,
+                Column::NullableBoolean(v) => v.iter().map(|x| x.map(|x| x.to_string())).collect(),
+                other => {
+                    return Err(DsError::Unsupported(format!(
+                        "partitioning by {:?} column '{}'",
+                        other.data_type(),
+                        name
+                    )));
+                }
+            };
+            return Ok((name.clone(), values));
+        }
+        PartitionKey::Year(source) => ("year", date_parts(df, source, &[DatePart::Year])?),
+        PartitionKey::Month(source) => ("month", date_parts(df, source, &[DatePart::Month])?),
+        PartitionKey::Date(source) => (
+            "date",
+            date_parts(
+                df,
+                source,
+                &[DatePart::Year, DatePart::Month, DatePart::Day],
+            )?,
+        ),
+    };
+
+    let values = (0..df.shape().0)
+        .map(|row| match parts.as_slice() {
+            [year, month, day] => Some(format!(
+                "{:04}-{:02}-{:02}",
+                year[row]?, month[row]?, day[row]?
+            )),
+            [part] => part[row].map(|v| v.to_string()),
+            _ => None,
+        })
+        .collect();
+    Ok((name.to_string(), values))
+}
+
+/// Calendar fields (UTC) of the timestamp column `source`, one Vec per part;
+/// NULL timestamps give None
+fn date_parts(
+    df: &dyn DataFrame,
+    source: &str,
+    parts: &[DatePart],
+) -> Result<Vec<Vec<Option<i32>>>, DsError> {
+    let column = if source == INDEX_NAME && df.has_index() {
+        Column::Timestamp(TimeUnit::Microsecond, df.read_index_microsecond()?)
+    } else {
+        df.read_column(source)?
+    };
+    let (Column::Timestamp(unit, _) | Column::NullableTimestamp(unit, _)) = column else {
+        return Err(DsError::TypeMismatch {
+            column: source.to_string(),
+            expected: "Timestamp".to_string(),
+            found: format!("{:?}", column.data_type()),
+        });
+    };
+
+    // Values are UTC; dropping the zone name spares a timezone database lookup
+    let array = cast(
+        &column_array(column, false),
+        &DataType::Timestamp(unit, None),
+    )?;
+    parts
+        .iter()
+        .map(|&part| {
+            let values = date_part(&array, part)?;
+            Ok(values.as_primitive::<Int32Type>().iter().collect())
+        })
+        .collect()
+}
+
+/// Escape characters that are unsafe in a `key=value` directory name as `%XX`
+fn percent_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_ascii_control() || "\"#%'*/:=?\\{}[]^<>|".contains(c) {
+            out.push_str(&format!("%{:02X}", c as u8));
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// One file of a multi-file frame
+struct Part {
+    path: String,
+    frame: Box<dyn DataFrame>,
+    rows: usize,
+    columns: HashSet<String>,
+    partitions: Partitions,
+}
+
+impl Part {
+    fn open(path: &str, partitions: Partitions) -> Result<Self, DsError> {
+        Ok(Self::new(path, read_parquet(path)?, partitions))
+    }
+
+    fn new(path: &str, frame: Box<dyn DataFrame>, partitions: Partitions) -> Self {
+        Self {
+            path: path.to_string(),
+            rows: frame.shape().0,
+            columns: frame.column_names().into_iter().collect(),
+            frame,
+            partitions,
+        }
+    }
+
+    fn partition(&self, key: &str) -> Option<&str> {
+        self.partitions
+            .iter()
+            .find(|(k, _)| k == key)
+            .and_then(|(_, v)| v.as_deref())
+    }
+}
+
+/// Files read as one frame: the rows of each file follow those of the previous one
+struct MultiFileDataFrame {
+    parts: Vec<Part>,
+    /// Unified file columns (index excluded) with their types
+    columns: Vec<(String, String)>,
+    /// Partition keys with their inferred types
+    partition_columns: Vec<(String, String)>,
+    row_count: usize,
+}
+
+impl MultiFileDataFrame {
+    /// Unify the part schemas by column name; with `strict`, every part must
+    /// have the columns, column order, types and index of the first one
+    fn new(parts: Vec<Part>, strict: bool) -> Result<Self, DsError> {
+        if strict {
+            check_same_schema(&parts)?;
+        }
+        let mut columns: Vec<(String, String)> = Vec::new();
+        let mut partition_keys: Vec<String> = Vec::new();
+
+        for part in &parts {
+            let types = part.frame.column_types();
+            for name in part.frame.column_names() {
+                let data_type = types.get(&name).cloned().unwrap_or_default();
+                match columns.iter().find(|(n, _)| *n == name) {
+                    Some((_, existing)) if *existing != data_type => {
+                        return Err(DsError::SchemaMismatch {
+                            path: part.path.clone(),
+                            message: format!(
+                                "column '{}' has type {}, other files have {}",
+                                name, data_type, existing
+                            ),
+                        });
+                    }
+                    Some(_) => {}
+                    None => columns.push((name, data_type)),
+                }
+            }
+            for (key, _) in &part.partitions {
+                if !partition_keys.contains(key) {
+                    partition_keys.push(key.clone());
+                }
+            }
+        }
+
+        let mut partition_columns = Vec::new();
+        for key in partition_keys {
+            if columns.iter().any(|(n, _)| *n == key) {
+                return Err(DsError::InvalidArgument(format!(
+                    "partition key '{}' is also a column in the files",
+                    key
+                )));
+            }
+            let values: Vec<&str> = parts.iter().filter_map(|p| p.partition(&key)).collect();
+            let data_type = if !values.is_empty() && values.iter().all(|v| v.parse::<i64>().is_ok())
+            {
+                "Int64"
+            } else {
+                "Utf8"
+            };
+            partition_columns.push((key, data_type.to_string()));
+        }
+
+        let row_count = parts.iter().map(|p| p.rows).sum();
+        Ok(Self {
+            parts,
+            columns,
+            partition_columns,
+            row_count,
+        })
+    }
+
+    fn partition_type(&self, column_name: &str) -> Option<&str> {
+        self.partition_columns
+            .iter()
+            .find(|(k, _)| k == column_name)
+            .map(|(_, t)| t.as_str())
+    }
+
+    fn column_type(&self, column_name: &str) -> Option<&str> {
+        self.columns
+            .iter()
+            .find(|(n, _)| n == column_name)
+            .map(|(_, t)| t.as_str())
+    }
+
+    /// Parts overlapping `rows`, with the first global row of each part and
+    /// the range local to it
+    fn part_ranges(&self, rows: &Range<usize>) -> Vec<(usize, usize, Range<usize>)> {
+        let mut out = Vec::new();
+        let mut start = 0;
+        for (i, part) in self.parts.iter().enumerate() {
+            let end = start + part.rows;
+            if start < rows.end && rows.start < end {
+                let local = rows.start.max(start) - start..rows.end.min(end) - start;
+                out.push((i, start, local));
+            }
+            start = end;
+        }
+        out
+    }
+
+    /// Concatenate the partition column `key` over the parts overlapping
+    /// `rows`, each part contributing `value` of its partition value
+    fn gather_partition<T: Clone>(
+        &self,
+        key: &str,
+        rows: Range<usize>,
+        value: impl Fn(Option<&str>) -> T,
+    ) -> Result<Vec<T>, DsError> {
+        check_row_range(&rows, self.row_count)?;
+        let mut values = Vec::with_capacity(rows.len());
+        for (i, _, local) in self.part_ranges(&rows) {
+            let value = value(self.parts[i].partition(key));
+            values.extend(std::iter::repeat_n(value, local.len()));
+        }
+        Ok(values)
+    }
+
+    /// Concatenate the file column `column_name` over the parts overlapping
+    /// `rows`, read with `read`; parts without the column contribute `missing`
+    fn gather_column<T: Clone>(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+        missing: T,
+        read: impl Fn(&dyn DataFrame, Range<usize>) -> Result<Vec<T>, DsError>,
+    ) -> Result<Vec<T>, DsError> {
+        check_row_range(&rows, self.row_count)?;
+        if self.column_type(column_name).is_none() {
+            return Err(DsError::ColumnNotFound(column_name.to_string()));
+        }
+        let mut values = Vec::with_capacity(rows.len());
+        for (i, _, local) in self.part_ranges(&rows) {
+            let part = &self.parts[i];
+            if part.columns.contains(column_name) {
+                values.extend(read(part.frame.as_ref(), local)?);
+            } else {
+                values.extend(std::iter::repeat_n(missing.clone(), local.len()));
+            }
+        }
+        Ok(values)
+    }
+
+    /// The partition column `key` over `len` rows of `part`
+    fn partition_column(&self, part: &Part, key: &str, len: usize) -> Column {
+        let value = part.partition(key);
+        if self.partition_type(key) == Some("Int64") {
+            match value.and_then(|v| v.parse::<i64>().ok()) {
+                Some(v) => Column::Int64(vec![v; len]),
+                None => Column::NullableInt64(vec![None; len]),
+            }
+        } else {
+            match value {
+                Some(v) => Column::Utf8(vec![v.to_string(); len]),
+                None => Column::NullableUtf8(vec![None; len]),
+            }
+        }
+    }
+
+    fn partition_mismatch(&self, column_name: &str, expected: &str) -> DsError {
+        DsError::TypeMismatch {
+            column: column_name.to_string(),
+            expected: expected.to_string(),
+            found: self
+                .partition_type(column_name)
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+}
+
+/// Check that every part has the schema of the first one
+fn check_same_schema(parts: &[Part]) -> Result<(), DsError> {
+    let Some(first) = parts.first() else {
+        return Err(DsError::InvalidArgument("no files to read".to_string()));
+    };
+    let names = first.frame.column_names();
+    let types = first.frame.column_types();
+    for part in &parts[1..] {
+        let mismatch = |message: String| DsError::SchemaMismatch {
+            path: part.path.clone(),
+            message,
+        };
+        let part_names = part.frame.column_names();
+        if part_names != names {
+            return Err(mismatch(format!(
+                "columns {:?} differ from {:?} in '{}'",
+                part_names, names, first.path
+            )));
+        }
+        let part_types = part.frame.column_types();
+        for name in &names {
+            if part_types.get(name) != types.get(name) {
+                return Err(mismatch(format!(
+                    "column '{}' has type {}, '{}' has {}",
+                    name,
+                    part_types.get(name).map_or("none", String::as_str),
+                    first.path,
+                    types.get(name).map_or("none", String::as_str)
+                )));
+            }
+        }
+        if part.frame.has_index() != first.frame.has_index() {
+            return Err(mismatch(format!(
+                "index presence differs from '{}'",
+                first.path
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// A column of NULLs of the type named `type_name`
+fn null_column(column_name: &str, type_name: &str, len: usize) -> Result<Column, DsError> {
+    match type_name {
+        "Int64" => Ok(Column::NullableInt64(vec![None; len])),
+        "Float64" => Ok(Column::NullableFloat64(vec![None; len])),
+        "Boolean" => Ok(Column::NullableBoolean(vec![None; len])),
+        t if t.contains("Utf8") => Ok(Column::NullableUtf8(vec![None; len])),
+        t => Err(DsError::Unsupported(format!(
+            "filling missing column '{}' of type {} with NULL",
+            column_name, t
+        ))),
+    }
+}
+
+impl DataFrame for MultiFileDataFrame {
+    fn shape(&self) -> (usize, usize) {
+        (
+            self.row_count,
+            self.columns.len() + self.partition_columns.len(),
+        )
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        self.columns
+            .iter()
+            .chain(&self.partition_columns)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    fn column_types(&self) -> HashMap<String, String> {
+        self.columns
+            .iter()
+            .chain(&self.partition_columns)
+            .cloned()
+            .collect()
+    }
+
+    fn has_index(&self) -> bool {
+        !self.parts.is_empty() && self.parts.iter().all(|p| p.frame.has_index())
+    }
+
+    fn column_summary(&self, column_name: &str) -> Result<ColumnSummary, DsError> {
+        if self.partition_type(column_name).is_some() {
+            return Ok(ColumnSummary::scan(&self.read_column(column_name)?));
+        }
+        if self.column_type(column_name).is_none() {
+            return Err(DsError::ColumnNotFound(column_name.to_string()));
+        }
+        let mut summary = ColumnSummary::all_null(0);
+        for part in &self.parts {
+            let part_summary = if part.columns.contains(column_name) {
+                part.frame.column_summary(column_name)?
+            } else {
+                ColumnSummary::all_null(part.rows)
+            };
+            summary = summary.merge(part_summary);
+        }
+        Ok(summary)
+    }
+
+    fn index_names(&self) -> Vec<String> {
+        self.parts
+            .first()
+            .map(|p| p.frame.index_names())
+            .unwrap_or_default()
+    }
+
+    fn index_fields(&self) -> Vec<(String, String)> {
+        self.parts
+            .first()
+            .map(|p| p.frame.index_fields())
+            .unwrap_or_default()
+    }
+
+    fn read_column(&self, column_name: &str) -> Result<Column, DsError> {
+        if self.partition_type(column_name).is_some() {
+            let columns = self
+                .parts
+                .iter()
+                .map(|part| self.partition_column(part, column_name, part.rows))
+                .collect();
+            return Column::concat(columns);
+        }
+
+        let Some(type_name) = self.column_type(column_name) else {
+            if !self.index_fields().iter().any(|(f, _)| f == column_name) {
+                return Err(DsError::ColumnNotFound(column_name.to_string()));
+            }
+            let columns = self
+                .parts
+                .iter()
+                .map(|part| part.frame.read_column(column_name))
+                .collect::<Result<_, _>>()?;
+            return Column::concat(columns);
+        };
+        let mut columns = Vec::with_capacity(self.parts.len());
+        for part in &self.parts {
+            if part.columns.contains(column_name) {
+                columns.push(part.frame.read_column(column_name)?);
+            } else {
+                columns.push(null_column(column_name, type_name, part.rows)?);
+            }
+        }
+        if columns.is_empty() {
+            return null_column(column_name, type_name, 0);
+        }
+        Column::concat(columns)
+    }
+
+    fn read_column_string(&self, column_name: &str) -> Result<Vec<String>, DsError> {
+        self.read_column_string_range(column_name, 0..self.row_count)
+    }
+
+    fn read_column_string_opt(&self, column_name: &str) -> Result<Vec<Option<String>>, DsError> {
+        self.read_column_string_opt_range(column_name, 0..self.row_count)
+    }
+
+    fn read_column_i64(&self, column_name: &str) -> Result<Vec<i64>, DsError> {
+        self.read_column_i64_range(column_name, 0..self.row_count)
+    }
+
+    fn read_column_f64(&self, column_name: &str) -> Result<Vec<f64>, DsError> {
+        self.read_column_f64_range(column_name, 0..self.row_count)
+    }
+
+    fn read_index_microsecond(&self) -> Result<Vec<i64>, DsError> {
+        self.read_index_microsecond_range(0..self.row_count)
+    }
+
+    fn read_column_string_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<String>, DsError> {
+        if self.partition_type(column_name) == Some("Int64") {
+            return Err(self.partition_mismatch(column_name, "Utf8"));
+        }
+        self.read_column_string_opt_range(column_name, rows.clone())?
+            .into_iter()
+            .enumerate()
+            .map(|(k, opt)| {
+                opt.ok_or_else(|| DsError::NullInNonNullable {
+                    column: column_name.to_string(),
+                    row: rows.start + k,
+                })
+            })
+            .collect()
+    }
+
+    fn read_column_string_opt_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<Option<String>>, DsError> {
+        if self.partition_type(column_name) == Some("Int64") {
+            return Err(self.partition_mismatch(column_name, "Utf8"));
+        }
+        if self.partition_type(column_name).is_some() {
+            return self.gather_partition(column_name, rows, |v| v.map(str::to_string));
+        }
+        self.gather_column(column_name, rows, None, |df, local| {
+            df.read_column_string_opt_range(column_name, local)
+        })
+    }
+
+    fn read_column_i64_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<i64>, DsError> {
+        if self.partition_type(column_name) == Some("Utf8") {
+            return Err(self.partition_mismatch(column_name, "Int64"));
+        }
+        if self.partition_type(column_name).is_some() {
+            return self.gather_partition(column_name, rows, |v| {
+                v.and_then(|v| v.parse::<i64>().ok()).unwrap_or(0)
+            });
+        }
+        self.gather_column(column_name, rows, 0, |df, local| {
+            df.read_column_i64_range(column_name, local)
+        })
+    }
+
+    fn read_column_f64_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<f64>, DsError> {
+        if self.partition_type(column_name).is_some() {
+            return Err(self.partition_mismatch(column_name, "Float64"));
+        }
+        self.gather_column(column_name, rows, f64::NAN, |df, local| {
+            df.read_column_f64_range(column_name, local)
+        })
+    }
+
+    fn read_column_timestamp_range(
+        &self,
+        column_name: &str,
+        unit: TimeUnit,
+        rows: Range<usize>,
+    ) -> Result<Vec<i64>, DsError> {
+        if self.partition_type(column_name) == Some("Utf8") {
+            return Err(self.partition_mismatch(column_name, "Timestamp"));
+        }
+        let start = rows.start;
+        let values = if self.partition_type(column_name).is_some() {
+            self.gather_partition(column_name, rows, |v| v.and_then(|v| v.parse::<i64>().ok()))?
+        } else {
+            self.gather_column(column_name, rows, None, |df, local| {
+                let values = df.read_column_timestamp_range(column_name, unit, local)?;
+                Ok(values.into_iter().map(Some).collect())
+            })?
+        };
+        convert_timestamps(column_name, start, values, unit, unit)
+    }
+
+    fn read_index_microsecond_range(&self, rows: Range<usize>) -> Result<Vec<i64>, DsError> {
+        check_row_range(&rows, self.row_count)?;
+        let mut values = Vec::with_capacity(rows.len());
+        for (i, _, local) in self.part_ranges(&rows) {
+            values.extend(self.parts[i].frame.read_index_microsecond_range(local)?);
+        }
+        Ok(values)
+    }
+
+    fn read_window(
+        &self,
+        start: i64,
+        end: i64,
+        column_names: &[&str],
+    ) -> Result<(Vec<i64>, Vec<Column>), DsError> {
+        let mut type_names = Vec::with_capacity(column_names.len());
+        for &name in column_names {
+            let type_name = self
+                .column_type(name)
+                .or_else(|| self.partition_type(name))
+                .ok_or_else(|| DsError::ColumnNotFound(name.to_string()))?;
+            type_names.push(type_name);
+        }
+
+        let mut index = Vec::new();
+        let mut pieces: Vec<Vec<Column>> = vec![Vec::new(); column_names.len()];
+        for part in &self.parts {
+            let present: Vec<&str> = column_names
+                .iter()
+                .copied()
+                .filter(|name| part.columns.contains(*name))
+                .collect();
+            let (part_index, part_columns) = part.frame.read_window(start, end, &present)?;
+            let mut part_columns = part_columns.into_iter();
+            for (k, &name) in column_names.iter().enumerate() {
+                let column = if self.partition_type(name).is_some() {
+                    self.partition_column(part, name, part_index.len())
+                } else if part.columns.contains(name) {
+                    part_columns.next().ok_or_else(|| {
+                        DsError::InvalidArgument(format!(
+                            "'{}' returned too few columns",
+                            part.path
+                        ))
+                    })?
+                } else {
+                    null_column(name, type_names[k], part_index.len())?
+                };
+                pieces[k].push(column);
+            }
+            index.extend(part_index);
+        }
+
+        let columns = pieces
+            .into_iter()
+            .zip(column_names.iter().zip(type_names))
+            .map(|(parts, (name, type_name))| {
+                if parts.is_empty() {
+                    null_column(name, type_name, 0)
+                } else {
+                    Column::concat(parts)
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((index, columns))
+    }
+
+    fn read_window_f64(
+        &self,
+        start: i64,
+        end: i64,
+        column_names: &[&str],
+    ) -> Result<(Vec<i64>, Vec<Vec<f64>>), DsError> {
+        for &name in column_names {
+            if self.partition_type(name).is_some() {
+                return Err(self.partition_mismatch(name, "Float64"));
+            }
+            if self.column_type(name).is_none() {
+                return Err(DsError::ColumnNotFound(name.to_string()));
+            }
+        }
+
+        let mut index = Vec::new();
+        let mut columns: Vec<Vec<f64>> = vec![Vec::new(); column_names.len()];
+        for part in &self.parts {
+            let present: Vec<&str> = column_names
+                .iter()
+                .copied()
+                .filter(|name| part.columns.contains(*name))
+                .collect();
+            let (part_index, mut part_columns) =
+                part.frame.read_window_f64(start, end, &present)?;
+            for (k, name) in column_names.iter().enumerate() {
+                if part.columns.contains(*name) {
+                    columns[k].append(&mut part_columns.remove(0));
+                } else {
+                    columns[k].extend(std::iter::repeat_n(f64::NAN, part_index.len()));
+                }
+            }
+            index.extend(part_index);
+        }
+        Ok((index, columns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::MemDataFrame;
+    use crate::parquet::write_parquet_columns;
+
+    /// An empty directory under the system temp dir, unique to this process
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ds_util_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Write a file with a microsecond `__index_level_0__` and the given columns
+    fn write_file(path: &Path, index: Vec<i64>, columns: Vec<(&str, Column)>) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let mut all = vec![(
+            INDEX_NAME.to_string(),
+            Column::Timestamp(TimeUnit::Microsecond, index),
+        )];
+        all.extend(
+            columns
+                .into_iter()
+                .map(|(name, col)| (name.to_string(), col)),
+        );
+        write_parquet_columns(
+            &path.to_string_lossy(),
+            all,
+            &ParquetWriteOptions::default(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn window_over_dataset_adds_partition_and_missing_columns() {
+        let dir = temp_dir("window_dataset");
+        write_file(
+            &dir.join("sym=a/part-0.parquet"),
+            vec![0, 10, 20],
+            vec![("x", Column::Float64(vec![0.0, 1.0, 2.0]))],
+        );
+        write_file(
+            &dir.join("sym=b/part-0.parquet"),
+            vec![5, 15, 25],
+            vec![
+                ("x", Column::Float64(vec![3.0, 4.0, 5.0])),
+                ("n", Column::Int64(vec![7, 8, 9])),
+            ],
+        );
+        let df = read_parquet_dataset(&dir.to_string_lossy(), &[]).unwrap();
+        let (index, columns) = df.read_window(10, 20, &["sym", "x", "n"]).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(index, vec![10, 15]);
+        assert_eq!(
+            columns,
+            vec![
+                Column::Utf8(vec!["a".to_string(), "b".to_string()]),
+                Column::Float64(vec![1.0, 4.0]),
+                Column::NullableInt64(vec![None, Some(8)]),
+            ]
+        );
+    }
+
+    /// Files `year=2025|2026/sym=a|b|__HIVE_DEFAULT_PARTITION__/part-0.parquet`,
+    /// each with one row whose `x` is the file's number
+    fn write_partitioned_dataset(name: &str) -> PathBuf {
+        let dir = temp_dir(name);
+        let mut i = 0;
+        for year in ["2025", "2026"] {
+            for sym in ["a", "b", HIVE_NULL] {
+                write_file(
+                    &dir.join(format!("year={}/sym={}/part-0.parquet", year, sym)),
+                    vec![i],
+                    vec![("x", Column::Int64(vec![i]))],
+                );
+                i += 1;
+            }
+        }
+        dir
+    }
+
+    #[test]
+    fn dataset_reads_partition_columns() {
+        let dir = write_partitioned_dataset("dataset_partitions");
+        let df = read_parquet_dataset(&dir.to_string_lossy(), &[]).unwrap();
+        let columns = ["x", "year", "sym"].map(|name| df.read_column(name).unwrap());
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(df.column_names(), vec!["x", "year", "sym"]);
+        assert_eq!(
+            columns,
+            [
+                Column::Int64(vec![2, 0, 1, 5, 3, 4]),
+                Column::Int64(vec![2025, 2025, 2025, 2026, 2026, 2026]),
+                Column::NullableUtf8(
+                    [None, Some("a"), Some("b"), None, Some("a"), Some("b")]
+                        .map(|v| v.map(String::from))
+                        .to_vec()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn dataset_filters_select_partitions() {
+        let dir = write_partitioned_dataset("dataset_filters");
+        let path = dir.to_string_lossy().to_string();
+        let read = |filters: &[(&str, &str)]| read_parquet_dataset(&path, filters).unwrap();
+        let read_x = |filters: &[(&str, &str)]| read(filters).read_column("x").unwrap();
+        let by_year = read_x(&[("year", "2026")]);
+        let either_sym = read_x(&[("sym", "a"), ("sym", "b")]);
+        let both_keys = read_x(&[("year", "2025"), ("sym", "b")]);
+        let no_match = read(&[("sym", "c")]).shape();
+        let unknown_key = read(&[("month", "1")]).shape();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(by_year, Column::Int64(vec![5, 3, 4]));
+        assert_eq!(either_sym, Column::Int64(vec![0, 1, 3, 4]));
+        assert_eq!(both_keys, Column::Int64(vec![1]));
+        // A NULL partition matches no value, nor does a key the files lack
+        assert_eq!(no_match.0, 0);
+        assert_eq!(unknown_key.0, 0);
+    }
+
+    /// A frame of `sym` and `x` columns, one row per `(sym, x)` pair
+    fn sym_frame(rows: &[(&str, i64)]) -> MemDataFrame {
+        MemDataFrame::builder()
+            .add_string("sym", rows.iter().map(|(s, _)| s.to_string()).collect())
+            .add_i64("x", rows.iter().map(|(_, x)| *x).collect())
+            .build()
+            .unwrap()
+    }
+
+    /// File names under each partition directory of `dir`, sorted
+    fn partition_files(dir: &Path) -> Vec<String> {
+        let mut files = Vec::new();
+        collect_files(dir, &[], &mut files).unwrap();
+        let mut names: Vec<String> = files
+            .into_iter()
+            .map(|(path, _)| {
+                let path = path.strip_prefix(dir).unwrap().to_string_lossy();
+                path.replace('\\', "/")
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn partitioned_write_modes() {
+        let dir = temp_dir("partition_modes");
+        let path = dir.to_string_lossy().to_string();
+        let by_sym = [PartitionKey::Column("sym".to_string())];
+        let with_mode = |mode| PartitionWriteOptions {
+            mode,
+            ..PartitionWriteOptions::default()
+        };
+        let write = |rows: &[(&str, i64)], mode| {
+            write_parquet_partitioned(&path, &sym_frame(rows), &by_sym, &with_mode(mode))
+        };
+        write(&[("a", 1), ("b", 2)], WriteMode::ErrorIfExists).unwrap();
+        let exists = write(&[("a", 3)], WriteMode::ErrorIfExists).unwrap_err();
+        write(&[("a", 4)], WriteMode::Append).unwrap();
+        let after_append = partition_files(&dir);
+        write(&[("a", 5), ("c", 6)], WriteMode::Overwrite).unwrap();
+        let after_overwrite = partition_files(&dir);
+        let df = read_parquet_dataset(&path, &[]).unwrap();
+        let (x, sym) = (df.read_column("x"), df.read_column("sym"));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(exists, DsError::InvalidArgument(_)));
+        assert_eq!(
+            after_append,
+            [
+                "sym=a/part-00000.parquet",
+                "sym=a/part-00001.parquet",
+                "sym=b/part-00000.parquet",
+            ]
+        );
+        // Only the partitions written are replaced, and no staging files remain
+        assert_eq!(
+            after_overwrite,
+            [
+                "sym=a/part-00000.parquet",
+                "sym=b/part-00000.parquet",
+                "sym=c/part-00000.parquet",
+            ]
+        );
+        assert_eq!(x.unwrap(), Column::Int64(vec![5, 2, 6]));
+        assert_eq!(
+            sym.unwrap(),
+            Column::Utf8(["a", "b", "c"].map(String::from).to_vec())
+        );
+    }
+
+    #[test]
+    fn failed_overwrite_keeps_old_files() {
+        let dir = temp_dir("partition_failed_overwrite");
+        let path = dir.to_string_lossy().to_string();
+        let by_sym = [PartitionKey::Column("sym".to_string())];
+        let frame = sym_frame(&[("a", 1), ("b", 2)]);
+        write_parquet_partitioned(&path, &frame, &by_sym, &PartitionWriteOptions::default())
+            .unwrap();
+        let failing = PartitionWriteOptions {
+            mode: WriteMode::Overwrite,
+            parquet: ParquetWriteOptions {
+                max_row_group_size: 0,
+                ..ParquetWriteOptions::default()
+            },
+            ..PartitionWriteOptions::default()
+        };
+        let error = write_parquet_partitioned(&path, &sym_frame(&[("a", 3)]), &by_sym, &failing)
+            .unwrap_err();
+        let entries: Vec<String> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        let x = read_parquet_dataset(&path, &[]).unwrap().read_column("x");
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(error, DsError::InvalidArgument(_)));
+        assert!(entries.iter().all(|name| !name.starts_with("_staging")));
+        assert_eq!(x.unwrap(), Column::Int64(vec![1, 2]));
+    }
+
+    #[test]
+    fn null_timestamps_go_to_default_partition() {
+        let dir = temp_dir("partition_null_dates");
+        let day = 86_400_000_000;
+        let frame = MemDataFrame::builder()
+            .add_column(
+                "ts",
+                Column::NullableTimestamp(TimeUnit::Microsecond, vec![Some(day), None]),
+            )
+            .add_i64("x", vec![1, 2])
+            .build()
+            .unwrap();
+        let by_date = [
+            PartitionKey::Year("ts".to_string()),
+            PartitionKey::Date("ts".to_string()),
+        ];
+        let written = write_parquet_partitioned(
+            &dir.to_string_lossy(),
+            &frame,
+            &by_date,
+            &PartitionWriteOptions::default(),
+        );
+        let files = partition_files(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(written.unwrap().len(), 2);
+        assert_eq!(
+            files,
+            [
+                "year=1970/date=1970-01-02/part-00000.parquet",
+                "year=__HIVE_DEFAULT_PARTITION__/date=__HIVE_DEFAULT_PARTITION__/part-00000.parquet",
+            ]
+        );
+    }
+
+    #[test]
+    fn many_files_concatenate_rows() {
+        let dir = temp_dir("many_concat");
+        let (first, second) = (dir.join("a.parquet"), dir.join("b.parquet"));
+        write_file(&first, vec![0, 1], vec![("x", Column::Int64(vec![1, 2]))]);
+        write_file(&second, vec![2], vec![("x", Column::Int64(vec![3]))]);
+        let df = read_parquet_many(&[&first.to_string_lossy(), &second.to_string_lossy()]).unwrap();
+        let (x, index) = (df.read_column("x"), df.read_index_microsecond());
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(df.shape(), (3, 1));
+        assert_eq!(x.unwrap(), Column::Int64(vec![1, 2, 3]));
+        assert_eq!(index.unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn many_files_reject_schema_mismatch() {
+        let dir = temp_dir("many_mismatch");
+        let paths = ["a", "b", "c"].map(|name| dir.join(format!("{}.parquet", name)));
+        write_file(&paths[0], vec![0], vec![("x", Column::Int64(vec![1]))]);
+        write_file(&paths[1], vec![1], vec![("x", Column::Float64(vec![2.0]))]);
+        write_file(&paths[2], vec![2], vec![("y", Column::Int64(vec![3]))]);
+        let [a, b, c] = paths.map(|path| path.to_string_lossy().to_string());
+        let types = read_parquet_many(&[&a, &b]).err();
+        let names = read_parquet_many(&[&a, &c]).err();
+        let none = read_parquet_many(&[]).err();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(types, Some(DsError::SchemaMismatch { path, .. }) if path == b));
+        assert!(matches!(names, Some(DsError::SchemaMismatch { path, .. }) if path == c));
+        assert!(matches!(none, Some(DsError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn csv_files_are_typed_together() {
+        let dir = temp_dir("csv_many");
+        let paths = ["a", "b"].map(|name| dir.join(format!("{}.csv", name)));
+        std::fs::write(&paths[0], "x,y,z\n1,,a\n2,,b\n").unwrap();
+        std::fs::write(&paths[1], "x,y,z\n2.5,7,c\n").unwrap();
+        let paths = paths.map(|path| path.to_string_lossy().to_string());
+        let df = read_csv_many(&[&paths[0], &paths[1]], &CsvReadOptions::new());
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let df = df.unwrap();
+        assert_eq!(df.shape(), (3, 3));
+        assert_eq!(
+            df.read_column("x").unwrap(),
+            Column::Float64(vec![1.0, 2.0, 2.5])
+        );
+        // Empty in the first file, integers in the second
+        assert_eq!(
+            df.read_column("y").unwrap(),
+            Column::NullableInt64(vec![None, None, Some(7)])
+        );
+    }
+
+    #[test]
+    fn wildcard_literal_and_question_mark() {
+        assert!(wildcard_match("part-0.parquet", "part-0.parquet"));
+        assert!(!wildcard_match("part-0.parquet", "part-1.parquet"));
+        assert!(wildcard_match("part-?.parquet", "part-7.parquet"));
+        assert!(!wildcard_match("part-?.parquet", "part-17.parquet"));
+    }
+
+    #[test]
+    fn wildcard_star_matches_any_run() {
+        assert!(wildcard_match("*.parquet", "a.parquet"));
+        assert!(wildcard_match("*.parquet", ".parquet"));
+        assert!(!wildcard_match("*.parquet", "a.parquet.tmp"));
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match("a*b*c", "axxbyyc"));
+        assert!(!wildcard_match("a*b*c", "axxbyy"));
+    }
+
+    #[test]
+    fn wildcard_star_backtracks() {
+        // The first `b` is not the one that lets the rest match
+        assert!(wildcard_match("*b.csv", "abb.csv"));
+        assert!(wildcard_match("*ab*", "aaab"));
+        assert!(wildcard_match("**x", "yx"));
+        assert!(!wildcard_match("*x?", "ax"));
+    }
+
+    #[test]
+    fn percent_decode_escapes() {
+        assert_eq!(percent_decode("plain"), "plain");
+        assert_eq!(percent_decode("a%20b%2Fc"), "a b/c");
+        assert_eq!(percent_decode("%3a%3A"), "::");
+        // Multi-byte UTF-8 sequences decode across escapes
+        assert_eq!(percent_decode("caf%C3%A9"), "café");
+    }
+
+    #[test]
+    fn percent_decode_keeps_invalid_escapes() {
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz%4"), "%zz%4");
+        assert_eq!(percent_decode("%%41"), "%A");
+    }
+
+    #[test]
+    fn percent_decode_inverts_percent_encode() {
+        for s in ["a=b/c", "50%", "x:y?z", "tab\there", "é#1"] {
+            assert_eq!(percent_decode(&percent_encode(s)), s);
+        }
+    }
+}
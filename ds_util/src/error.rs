@@ -0,0 +1,121 @@
+use std::fmt;
+
+use arrow::error::ArrowError;
+use parquet::errors::ParquetError;
+
+/// Error type returned by all fallible ds_util functions
+#[derive(Debug)]
+pub enum DsError {
+    /// The requested column does not exist
+    ColumnNotFound(String),
+    /// The column exists but holds a different type than requested
+    TypeMismatch {
+        column: String,
+        expected: String,
+        found: String,
+    },
+    /// A NULL was found in a column read as non-nullable; `row` is 0-based
+    NullInNonNullable {
+        column: String,
+        row: usize,
+    },
+    Io(std::io::Error),
+    Parquet(ParquetError),
+    Arrow(ArrowError),
+    /// Malformed CSV input or a value that does not parse as its column's type
+    CsvParse(CsvParseError),
+    /// The operation is not supported by this frame
+    Unsupported(String),
+    /// An argument is out of range or inconsistent
+    InvalidArgument(String),
+    /// The schema of the file at `path` does not match the expected one
+    SchemaMismatch {
+        path: String,
+        message: String,
+    },
+}
+
+/// Where and why a CSV file failed to parse
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvParseError {
+    pub path: String,
+    /// 1-based line the record starts on
+    pub line: usize,
+    /// None when the error concerns the whole record, e.g. an unterminated quote
+    pub column: Option<String>,
+    /// Raw text of the offending field; None when the field is missing
+    pub value: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for CsvParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.path, self.line)?;
+        if let Some(column) = &self.column {
+            write!(f, ", column '{}'", column)?;
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(value) = &self.value {
+            write!(f, " (raw text '{}')", value)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DsError::ColumnNotFound(name) => write!(f, "column '{}' not found", name),
+            DsError::TypeMismatch {
+                column,
+                expected,
+                found,
+            } => write!(
+                f,
+                "column '{}' has type {}, expected {}",
+                column, found, expected
+            ),
+            DsError::NullInNonNullable { column, row } => {
+                write!(f, "column '{}' contains a NULL at row {}", column, row)
+            }
+            DsError::Io(e) => write!(f, "I/O error: {}", e),
+            DsError::Parquet(e) => write!(f, "parquet error: {}", e),
+            DsError::Arrow(e) => write!(f, "arrow error: {}", e),
+            DsError::CsvParse(e) => write!(f, "CSV parse error at {}", e),
+            DsError::Unsupported(what) => write!(f, "not supported: {}", what),
+            DsError::InvalidArgument(message) => write!(f, "invalid argument: {}", message),
+            DsError::SchemaMismatch { path, message } => {
+                write!(f, "schema mismatch in '{}': {}", path, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DsError::Io(e) => Some(e),
+            DsError::Parquet(e) => Some(e),
+            DsError::Arrow(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DsError {
+    fn from(e: std::io::Error) -> Self {
+        DsError::Io(e)
+    }
+}
+
+impl From<ParquetError> for DsError {
+    fn from(e: ParquetError) -> Self {
+        DsError::Parquet(e)
+    }
+}
+
+impl From<ArrowError> for DsError {
+    fn from(e: ArrowError) -> Self {
+        DsError::Arrow(e)
+    }
+}
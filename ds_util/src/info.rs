@@ -0,0 +1,167 @@
+use std::fs::File;
+
+use parquet::basic::{Compression, ConvertedType, Encoding, LogicalType};
+use parquet::file::metadata::ColumnChunkMetaData;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::file::statistics::Statistics;
+
+use crate::error::DsError;
+
+/// File-level metadata of a Parquet file, read from the footer only
+#[derive(Debug, Clone)]
+pub struct ParquetInfo {
+    pub num_rows: i64,
+    /// Format version written in the footer
+    pub version: i32,
+    pub created_by: Option<String>,
+    /// Key-value metadata in footer order, e.g. `pandas` and `ARROW:schema`
+    pub key_value_metadata: Vec<(String, Option<String>)>,
+    pub row_groups: Vec<RowGroupInfo>,
+}
+
+/// Metadata of one row group
+#[derive(Debug, Clone)]
+pub struct RowGroupInfo {
+    pub num_rows: i64,
+    /// Uncompressed size of all column data in bytes
+    pub total_byte_size: i64,
+    /// Compressed size of all column chunks in bytes
+    pub compressed_size: i64,
+    pub columns: Vec<ColumnChunkInfo>,
+}
+
+/// Metadata of one column within a row group
+#[derive(Debug, Clone)]
+pub struct ColumnChunkInfo {
+    /// Dot-separated column path, e.g. `x` or `a.b` for nested columns
+    pub name: String,
+    /// Parquet physical type, e.g. `INT64` or `BYTE_ARRAY`
+    pub physical_type: String,
+    pub compression: Compression,
+    pub encodings: Vec<Encoding>,
+    pub compressed_size: i64,
+    pub uncompressed_size: i64,
+    /// None when the writer stored no statistics for the chunk
+    pub statistics: Option<ColumnStatistics>,
+}
+
+/// Statistics of a column chunk; each field is None when not recorded
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStatistics {
+    pub min: Option<StatValue>,
+    pub max: Option<StatValue>,
+    pub null_count: Option<u64>,
+    pub distinct_count: Option<u64>,
+}
+
+/// A min or max statistic in its physical representation: timestamps are
+/// counts since the epoch in the column's unit and INT96 values nanoseconds
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatValue {
+    Boolean(bool),
+    Int(i64),
+    Float(f64),
+    Utf8(String),
+    Bytes(Vec<u8>),
+}
+
+/// Read the footer of the Parquet file at `path` without decoding any data
+pub fn read_parquet_info(path: &str) -> Result<ParquetInfo, DsError> {
+    let reader = SerializedFileReader::new(File::open(path)?)?;
+    let meta = reader.metadata();
+    let file_meta = meta.file_metadata();
+
+    let key_value_metadata = file_meta
+        .key_value_metadata()
+        .map(|kvs| {
+            kvs.iter()
+                .map(|kv| (kv.key.clone(), kv.value.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let row_groups = meta
+        .row_groups()
+        .iter()
+        .map(|rg| RowGroupInfo {
+            num_rows: rg.num_rows(),
+            total_byte_size: rg.total_byte_size(),
+            compressed_size: rg.compressed_size(),
+            columns: rg.columns().iter().map(column_chunk_info).collect(),
+        })
+        .collect();
+
+    Ok(ParquetInfo {
+        num_rows: file_meta.num_rows(),
+        version: file_meta.version(),
+        created_by: file_meta.created_by().map(str::to_string),
+        key_value_metadata,
+        row_groups,
+    })
+}
+
+fn column_chunk_info(column: &ColumnChunkMetaData) -> ColumnChunkInfo {
+    let descr = column.column_descr();
+    let is_string = descr.converted_type() == ConvertedType::UTF8
+        || matches!(descr.logical_type_ref(), Some(LogicalType::String));
+
+    ColumnChunkInfo {
+        name: column.column_path().string(),
+        physical_type: column.column_type().to_string(),
+        compression: column.compression(),
+        encodings: column.encodings().collect(),
+        compressed_size: column.compressed_size(),
+        uncompressed_size: column.uncompressed_size(),
+        statistics: column.statistics().map(|stats| {
+            let (min, max) = stat_values(stats, is_string);
+            ColumnStatistics {
+                min,
+                max,
+                null_count: stats.null_count_opt(),
+                distinct_count: stats.distinct_count_opt(),
+            }
+        }),
+    }
+}
+
+/// Min and max of `stats`; byte arrays become Utf8 for string columns
+fn stat_values(stats: &Statistics, is_string: bool) -> (Option<StatValue>, Option<StatValue>) {
+    let bytes = |b: &[u8]| match std::str::from_utf8(b) {
+        Ok(s) if is_string => StatValue::Utf8(s.to_string()),
+        _ => StatValue::Bytes(b.to_vec()),
+    };
+    match stats {
+        Statistics::Boolean(s) => (
+            s.min_opt().map(|v| StatValue::Boolean(*v)),
+            s.max_opt().map(|v| StatValue::Boolean(*v)),
+        ),
+        Statistics::Int32(s) => (
+            s.min_opt().map(|v| StatValue::Int(i64::from(*v))),
+            s.max_opt().map(|v| StatValue::Int(i64::from(*v))),
+        ),
+        Statistics::Int64(s) => (
+            s.min_opt().map(|v| StatValue::Int(*v)),
+            s.max_opt().map(|v| StatValue::Int(*v)),
+        ),
+        Statistics::Int96(s) => (
+            s.min_opt().map(|v| StatValue::Int(v.to_nanos())),
+            s.max_opt().map(|v| StatValue::Int(v.to_nanos())),
+        ),
+        Statistics::Float(s) => (
+            s.min_opt().map(|v| StatValue::Float(f64::from(*v))),
+            s.max_opt().map(|v| StatValue::Float(f64::from(*v))),
+        ),
+        Statistics::Double(s) => (
+            s.min_opt().map(|v| StatValue::Float(*v)),
+            s.max_opt().map(|v| StatValue::Float(*v)),
+        ),
+        Statistics::ByteArray(s) => (
+            s.min_opt().map(|v| bytes(v.data())),
+            s.max_opt().map(|v| bytes(v.data())),
+        ),
+        Statistics::FixedLenByteArray(s) => (
+            s.min_opt().map(|v| StatValue::Bytes(v.data().to_vec())),
+            s.max_opt().map(|v| StatValue::Bytes(v.data().to_vec())),
+        ),
+    }
+}
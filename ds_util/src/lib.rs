@@ -3,10 +3,29 @@
 
 mod csv;
 mod dataframe;
+mod dataset;
+mod error;
+mod info;
+mod memory;
 mod parquet;
 mod stats;
 
-pub use csv::read_csv;
-pub use dataframe::DataFrame;
-pub use parquet::{read_parquet, write_parquet};
+pub use ::parquet::basic::{Compression, Encoding, ZstdLevel};
+pub use ::parquet::file::properties::EnabledStatistics;
+pub use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+pub use csv::{CsvReadOptions, read_csv, read_csv_report};
+pub use dataframe::{Column, ColumnSummary, DataFrame, Scalar};
+pub use dataset::{
+    PartitionKey, PartitionWriteOptions, WriteMode, read_csv_many, read_parquet_dataset,
+    read_parquet_glob, read_parquet_many, write_parquet_partitioned,
+};
+pub use error::{CsvParseError, DsError};
+pub use info::{
+    ColumnChunkInfo, ColumnStatistics, ParquetInfo, RowGroupInfo, StatValue, read_parquet_info,
+};
+pub use memory::{MemDataFrame, MemDataFrameBuilder};
+pub use parquet::{
+    ParquetStreamWriter, ParquetWriteOptions, append_parquet, append_parquet_dataset, read_parquet,
+    write_parquet, write_parquet_columns, write_parquet_dataframe,
+};
 pub use stats::{get_corr, get_mean, get_percentile, get_percentiles};
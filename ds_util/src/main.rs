@@ -12,16 +12,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let input_parquet_path = &args[1];
     let output_parquet_path = &args[3];
 
-    let df = ds_util::read_parquet(&input_parquet_path)?;
+    let df = ds_util::read_parquet(input_parquet_path)?;
 
     println!("shape   : {:?}", df.shape());
     println!("names   : {:?}", df.column_names());
     println!("types   : {:?}", df.column_types());
 
-    let v = df.read_column_f64(&df.column_names()[0]);
+    let v = df.read_column_f64(&df.column_names()[0])?;
     dbg!(&v[0..10]);
 
-    let index = df.read_index_microsecond();
+    let index = df.read_index_microsecond()?;
     dbg!(&index[0..10]);
 
     // Write out to a parquet file.
@@ -40,6 +40,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         Some(int_data),
         Some(data),
         None,
+        &ds_util::ParquetWriteOptions::default(),
     )?;
 
     println!("Data written to the output parquet file successfully!");
@@ -0,0 +1,224 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use crate::dataframe::{Column, DataFrame, check_row_range};
+use crate::error::DsError;
+
+/// A DataFrame held in memory, built from Rust vectors with `MemDataFrame::builder()`
+#[derive(Debug, Clone)]
+pub struct MemDataFrame {
+    columns: Vec<(String, Column)>,
+    index: Option<Vec<i64>>,
+    row_count: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct MemDataFrameBuilder {
+    columns: Vec<(String, Column)>,
+    index: Option<Vec<i64>>,
+}
+
+impl MemDataFrame {
+    pub fn builder() -> MemDataFrameBuilder {
+        MemDataFrameBuilder::default()
+    }
+
+    pub fn column(&self, column_name: &str) -> Result<&Column, DsError> {
+        self.columns
+            .iter()
+            .find(|(name, _)| name == column_name)
+            .map(|(_, col)| col)
+            .ok_or_else(|| DsError::ColumnNotFound(column_name.to_string()))
+    }
+
+    fn index(&self) -> Result<&Vec<i64>, DsError> {
+        self.index
+            .as_ref()
+            .ok_or_else(|| DsError::Unsupported("MemDataFrame has no index".to_string()))
+    }
+}
+
+impl MemDataFrameBuilder {
+    /// Add a column; columns keep the order in which they are added
+    pub fn add_column<S: Into<String>>(mut self, name: S, column: Column) -> Self {
+        self.columns.push((name.into(), column));
+        self
+    }
+
+    pub fn add_f64<S: Into<String>>(self, name: S, values: Vec<f64>) -> Self {
+        self.add_column(name, Column::Float64(values))
+    }
+
+    pub fn add_i64<S: Into<String>>(self, name: S, values: Vec<i64>) -> Self {
+        self.add_column(name, Column::Int64(values))
+    }
+
+    pub fn add_string<S: Into<String>>(self, name: S, values: Vec<String>) -> Self {
+        self.add_column(name, Column::Utf8(values))
+    }
+
+    /// Set the index as microseconds since the epoch (UTC)
+    pub fn set_index(mut self, index: Vec<i64>) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Check that names are unique and all columns and the index have equal length
+    pub fn build(self) -> Result<MemDataFrame, DsError> {
+        let mut seen = HashSet::new();
+        for (name, _) in &self.columns {
+            if !seen.insert(name.as_str()) {
+                return Err(DsError::InvalidArgument(format!(
+                    "duplicate column name '{}'",
+                    name
+                )));
+            }
+        }
+
+        let row_count = match (&self.index, self.columns.first()) {
+            (Some(index), _) => index.len(),
+            (None, Some((_, col))) => col.len(),
+            (None, None) => 0,
+        };
+        for (name, col) in &self.columns {
+            if col.len() != row_count {
+                return Err(DsError::InvalidArgument(format!(
+                    "column '{}' has {} rows, expected {}",
+                    name,
+                    col.len(),
+                    row_count
+                )));
+            }
+        }
+
+        Ok(MemDataFrame {
+            columns: self.columns,
+            index: self.index,
+            row_count,
+        })
+    }
+}
+
+fn type_mismatch(column_name: &str, expected: &str, col: &Column) -> DsError {
+    DsError::TypeMismatch {
+        column: column_name.to_string(),
+        expected: expected.to_string(),
+        found: format!("{:?}", col.data_type()),
+    }
+}
+
+impl DataFrame for MemDataFrame {
+    fn shape(&self) -> (usize, usize) {
+        (self.row_count, self.columns.len())
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        self.columns.iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    fn column_types(&self) -> HashMap<String, String> {
+        self.columns
+            .iter()
+            .map(|(name, col)| (name.clone(), format!("{:?}", col.data_type())))
+            .collect()
+    }
+
+    fn has_index(&self) -> bool {
+        self.index.is_some()
+    }
+
+    fn read_column(&self, column_name: &str) -> Result<Column, DsError> {
+        Ok(self.column(column_name)?.clone())
+    }
+
+    fn read_column_string(&self, column_name: &str) -> Result<Vec<String>, DsError> {
+        self.read_column_string_range(column_name, 0..self.row_count)
+    }
+
+    fn read_column_i64(&self, column_name: &str) -> Result<Vec<i64>, DsError> {
+        self.read_column_i64_range(column_name, 0..self.row_count)
+    }
+
+    fn read_column_f64(&self, column_name: &str) -> Result<Vec<f64>, DsError> {
+        self.read_column_f64_range(column_name, 0..self.row_count)
+    }
+
+    fn read_index_microsecond(&self) -> Result<Vec<i64>, DsError> {
+        Ok(self.index()?.clone())
+    }
+
+    fn read_column_string_opt(&self, column_name: &str) -> Result<Vec<Option<String>>, DsError> {
+        self.read_column_string_opt_range(column_name, 0..self.row_count)
+    }
+
+    fn read_column_string_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<String>, DsError> {
+        check_row_range(&rows, self.row_count)?;
+        match self.column(column_name)? {
+            Column::Utf8(v) => Ok(v[rows].to_vec()),
+            Column::NullableUtf8(v) => {
+                let start = rows.start;
+                v[rows]
+                    .iter()
+                    .enumerate()
+                    .map(|(k, opt)| {
+                        opt.clone().ok_or_else(|| DsError::NullInNonNullable {
+                            column: column_name.to_string(),
+                            row: start + k,
+                        })
+                    })
+                    .collect()
+            }
+            other => Err(type_mismatch(column_name, "Utf8", other)),
+        }
+    }
+
+    fn read_column_string_opt_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<Option<String>>, DsError> {
+        check_row_range(&rows, self.row_count)?;
+        match self.column(column_name)? {
+            Column::Utf8(v) => Ok(v[rows].iter().cloned().map(Some).collect()),
+            Column::NullableUtf8(v) => Ok(v[rows].to_vec()),
+            other => Err(type_mismatch(column_name, "Utf8", other)),
+        }
+    }
+
+    fn read_column_i64_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<i64>, DsError> {
+        check_row_range(&rows, self.row_count)?;
+        match self.column(column_name)? {
+            Column::Int64(v) => Ok(v[rows].to_vec()),
+            Column::NullableInt64(v) => Ok(v[rows].iter().map(|x| x.unwrap_or(0)).collect()),
+            other => Err(type_mismatch(column_name, "Int64", other)),
+        }
+    }
+
+    fn read_column_f64_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<f64>, DsError> {
+        check_row_range(&rows, self.row_count)?;
+        match self.column(column_name)? {
+            Column::Float64(v) => Ok(v[rows].to_vec()),
+            Column::NullableFloat64(v) => {
+                Ok(v[rows].iter().map(|x| x.unwrap_or(f64::NAN)).collect())
+            }
+            other => Err(type_mismatch(column_name, "Float64", other)),
+        }
+    }
+
+    fn read_index_microsecond_range(&self, rows: Range<usize>) -> Result<Vec<i64>, DsError> {
+        check_row_range(&rows, self.row_count)?;
+        Ok(self.index()?[rows].to_vec())
+    }
+}
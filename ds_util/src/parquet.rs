@@ -1,20 +1,43 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use arrow::array::{Array, ArrayRef, Float64Array, Int64Array, StringArray, TimestampSecondArray};
-use arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use arrow::array::{
+    Array, ArrayRef, AsArray, BooleanArray, Float64Array, Int64Array, StringArray,
+    TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
+    TimestampSecondArray,
+};
+use arrow::compute::{cast, filter};
+use arrow::datatypes::{
+    ArrowPrimitiveType, DataType, Field, Float64Type, Int64Type, Schema, SchemaRef, TimeUnit,
+};
 use arrow::record_batch::RecordBatch;
-use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ProjectionMask;
+use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
+use parquet::arrow::arrow_reader::{
+    ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowSelection, RowSelector,
+};
 use parquet::arrow::arrow_writer::ArrowWriter;
-use parquet::file::properties::WriterProperties;
+use parquet::basic::Compression;
+use parquet::file::metadata::{KeyValue, ParquetMetaData};
+use parquet::file::properties::{
+    DEFAULT_COMPRESSION, DEFAULT_DICTIONARY_ENABLED, DEFAULT_MAX_ROW_GROUP_SIZE, DEFAULT_PAGE_SIZE,
+    DEFAULT_STATISTICS_ENABLED, EnabledStatistics, WriterProperties,
+};
 use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::schema::types::{ColumnPath, SchemaDescriptor};
+use serde_json::{Value, json};
 
-use crate::dataframe::DataFrame;
+use crate::dataframe::{
+    Column, ColumnSummary, DataFrame, Scalar, check_row_range, convert_timestamps,
+};
+use crate::error::DsError;
 
-const INDEX_NAME: &str = "__index_level_0__";
+pub(crate) const INDEX_NAME: &str = "__index_level_0__";
 
-pub fn read_parquet(path: &str) -> Result<Box<dyn DataFrame>, Box<dyn std::error::Error>> {
+pub fn read_parquet(path: &str) -> Result<Box<dyn DataFrame>, DsError> {
     let file = File::open(path)?;
     let file_for_schema = file.try_clone()?;
 
@@ -28,6 +51,7 @@ pub fn read_parquet(path: &str) -> Result<Box<dyn DataFrame>, Box<dyn std::error
 
     Ok(Box::new(ArrowDataFrame {
         path: path.to_string(),
+        index_levels: index_levels(&arrow_schema),
         schema: arrow_schema,
         row_count,
     }))
@@ -37,320 +61,1848 @@ struct ArrowDataFrame {
     path: String,
     schema: SchemaRef,
     row_count: usize,
+    index_levels: Vec<IndexLevel>,
+}
+
+/// An index level of a file written by pandas
+#[derive(Debug, Clone)]
+enum IndexLevel {
+    /// Stored as the file column `field`
+    Stored { name: String, field: String },
+    /// A RangeIndex, described by the metadata only
+    Range { name: String, start: i64, step: i64 },
+}
+
+impl IndexLevel {
+    fn name(&self) -> &str {
+        match self {
+            IndexLevel::Stored { name, .. } | IndexLevel::Range { name, .. } => name,
+        }
+    }
+}
+
+/// Index levels from the `pandas` schema metadata, falling back to a
+/// `__index_level_0__` column when the metadata is missing or unreadable
+fn index_levels(schema: &Schema) -> Vec<IndexLevel> {
+    if let Some(levels) = schema
+        .metadata()
+        .get("pandas")
+        .and_then(|json| pandas_index_levels(json, schema))
+    {
+        return levels;
+    }
+    if schema.index_of(INDEX_NAME).is_ok() {
+        vec![IndexLevel::Stored {
+            name: INDEX_NAME.to_string(),
+            field: INDEX_NAME.to_string(),
+        }]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Parse the `index_columns` of pandas metadata. Unnamed levels are called
+/// `__index_level_N__` as pandas does.
+fn pandas_index_levels(json: &str, schema: &Schema) -> Option<Vec<IndexLevel>> {
+    let meta: Value = serde_json::from_str(json).ok()?;
+    let columns = meta.get("columns").and_then(Value::as_array);
+
+    let mut levels = Vec::new();
+    for (i, entry) in meta.get("index_columns")?.as_array()?.iter().enumerate() {
+        let unnamed = format!("__index_level_{}__", i);
+        match entry {
+            Value::String(field) => {
+                schema.index_of(field).ok()?;
+                let name = columns
+                    .and_then(|cols| {
+                        cols.iter().find(|c| {
+                            c.get("field_name").and_then(Value::as_str) == Some(field.as_str())
+                        })
+                    })
+                    .and_then(|c| c.get("name"))
+                    .and_then(Value::as_str)
+                    .unwrap_or(&unnamed);
+                levels.push(IndexLevel::Stored {
+                    name: name.to_string(),
+                    field: field.clone(),
+                });
+            }
+            Value::Object(range) if range.get("kind")?.as_str()? == "range" => {
+                let name = range
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or(&unnamed);
+                levels.push(IndexLevel::Range {
+                    name: name.to_string(),
+                    start: range.get("start")?.as_i64()?,
+                    step: range.get("step").and_then(Value::as_i64).unwrap_or(1),
+                });
+            }
+            _ => return None,
+        }
+    }
+    Some(levels)
+}
+
+impl ArrowDataFrame {
+    /// Look up a column and its data type in the file schema
+    fn field(&self, column_name: &str) -> Result<(usize, &DataType), DsError> {
+        let idx = self
+            .schema
+            .index_of(column_name)
+            .map_err(|_| DsError::ColumnNotFound(column_name.to_string()))?;
+        Ok((idx, self.schema.field(idx).data_type()))
+    }
+
+    fn open_builder(
+        &self,
+        page_index: bool,
+    ) -> Result<ParquetRecordBatchReaderBuilder<File>, DsError> {
+        let file = File::open(&self.path)?;
+        let options = ArrowReaderOptions::new().with_page_index(page_index);
+        Ok(ParquetRecordBatchReaderBuilder::try_new_with_options(
+            file, options,
+        )?)
+    }
+
+    /// Decode only the columns at `indices` and rows in `rows`, returning their
+    /// arrays (one per record batch) in the requested order
+    fn read_arrays(
+        &self,
+        indices: &[usize],
+        rows: Range<usize>,
+    ) -> Result<Vec<Vec<ArrayRef>>, DsError> {
+        check_row_range(&rows, self.row_count)?;
+
+        let builder = self.open_builder(false)?;
+        let plan = if rows != (0..self.row_count) {
+            Some(select_row_range(builder.metadata(), &rows))
+        } else {
+            None
+        };
+        self.read_arrays_from(builder, indices, plan)
+    }
+
+    /// Decode the columns at `indices`, restricted to the given row groups and
+    /// row selection if any
+    fn read_arrays_from(
+        &self,
+        builder: ParquetRecordBatchReaderBuilder<File>,
+        indices: &[usize],
+        plan: Option<(Vec<usize>, RowSelection)>,
+    ) -> Result<Vec<Vec<ArrayRef>>, DsError> {
+        let mask = ProjectionMask::roots(builder.parquet_schema(), indices.iter().copied());
+        let mut builder = builder.with_projection(mask);
+        if let Some((row_groups, selection)) = plan {
+            builder = builder
+                .with_row_groups(row_groups)
+                .with_row_selection(selection);
+        }
+        let reader = builder.build()?;
+
+        let mut arrays: Vec<Vec<ArrayRef>> = vec![Vec::new(); indices.len()];
+        for batch in reader {
+            let batch = batch?;
+            for (k, &idx) in indices.iter().enumerate() {
+                let name = self.schema.field(idx).name();
+                let array = batch
+                    .column_by_name(name)
+                    .ok_or_else(|| DsError::ColumnNotFound(name.to_string()))?;
+                arrays[k].push(array.clone());
+            }
+        }
+        Ok(arrays)
+    }
+
+    /// The first stored index level holding timestamps or dates
+    fn time_index_field(&self) -> Option<&str> {
+        self.index_levels.iter().find_map(|level| match level {
+            IndexLevel::Stored { field, .. } => {
+                let data_type = self.schema.field_with_name(field).ok()?.data_type();
+                matches!(
+                    data_type,
+                    DataType::Timestamp(..) | DataType::Date32 | DataType::Date64
+                )
+                .then_some(field.as_str())
+            }
+            IndexLevel::Range { .. } => None,
+        })
+    }
+
+    /// Values of the index level `name` if it is a RangeIndex
+    fn range_index(&self, name: &str, rows: &Range<usize>) -> Option<Vec<i64>> {
+        self.index_levels.iter().find_map(|level| match level {
+            IndexLevel::Range {
+                name: n,
+                start,
+                step,
+            } if n == name => Some(rows.clone().map(|i| start + i as i64 * step).collect()),
+            _ => None,
+        })
+    }
+
+    /// Summary of a column from its row group statistics; None when a row
+    /// group lacks statistics, they may be inexact, or the type is not covered
+    fn footer_summary(&self, column_name: &str) -> Result<Option<ColumnSummary>, DsError> {
+        let builder = self.open_builder(false)?;
+        let converter =
+            StatisticsConverter::try_new(column_name, &self.schema, builder.parquet_schema())?;
+        let Some(leaf) = converter.parquet_column_index() else {
+            return Ok(None);
+        };
+        let row_groups = builder.metadata().row_groups();
+        let (Some(mins), Some(maxes)) = (
+            stat_scalars(&converter.row_group_mins(row_groups)?)?,
+            stat_scalars(&converter.row_group_maxes(row_groups)?)?,
+        ) else {
+            return Ok(None);
+        };
+
+        let mut summary = ColumnSummary::all_null(0);
+        for (i, row_group) in row_groups.iter().enumerate() {
+            let Some(stats) = row_group.column(leaf).statistics() else {
+                return Ok(None);
+            };
+            let Some(nulls) = stats.null_count_opt() else {
+                return Ok(None);
+            };
+            let rows = row_group.num_rows() as usize;
+            let part = if nulls as usize == rows {
+                ColumnSummary::all_null(rows)
+            } else {
+                // Float min/max skip NaN, so a chunk of only NaN has none
+                if !(stats.min_is_exact() && stats.max_is_exact())
+                    || mins[i].is_none()
+                    || maxes[i].is_none()
+                {
+                    return Ok(None);
+                }
+                ColumnSummary {
+                    count: rows - nulls as usize,
+                    null_count: nulls as usize,
+                    min: mins[i].clone(),
+                    max: maxes[i].clone(),
+                }
+            };
+            summary = summary.merge(part);
+        }
+        Ok(Some(summary))
+    }
+
+    /// The time index: a stored timestamp index level, else the `timestamp`
+    /// column written by `write_parquet`
+    fn time_index(&self) -> Result<(usize, TimeUnit), DsError> {
+        let name = self.time_index_field().unwrap_or("timestamp");
+        let (idx, data_type) = self.field(name)?;
+        match data_type {
+            DataType::Timestamp(unit, _) => Ok((idx, *unit)),
+            other => Err(type_mismatch(name, "Timestamp", other)),
+        }
+    }
+
+    /// Decode a single column, one array per record batch
+    fn read_column_arrays(&self, idx: usize, rows: Range<usize>) -> Result<Vec<ArrayRef>, DsError> {
+        Ok(self.read_arrays(&[idx], rows)?.remove(0))
+    }
+
+    /// Read a primitive column of type `T`, keeping NULL as None
+    fn read_primitive_opt<T: ArrowPrimitiveType>(
+        &self,
+        idx: usize,
+        rows: Range<usize>,
+    ) -> Result<Vec<Option<T::Native>>, DsError> {
+        let mut values = Vec::with_capacity(rows.len());
+        for array in self.read_column_arrays(idx, rows)? {
+            values.extend(array.as_primitive::<T>().iter());
+        }
+        Ok(values)
+    }
+
+    /// Read Float64 columns restricted to `rows`, in the order of `column_names`
+    fn read_columns_f64_rows(
+        &self,
+        column_names: &[&str],
+        rows: Range<usize>,
+    ) -> Result<Vec<Vec<f64>>, DsError> {
+        let mut indices = Vec::with_capacity(column_names.len());
+        for &column_name in column_names {
+            let (idx, data_type) = self.field(column_name)?;
+            if *data_type != DataType::Float64 {
+                return Err(type_mismatch(column_name, "Float64", data_type));
+            }
+            indices.push(idx);
+        }
+
+        let capacity = rows.len();
+        Ok(self
+            .read_arrays(&indices, rows)?
+            .iter()
+            .map(|arrays| f64_values(arrays, capacity))
+            .collect())
+    }
+}
+
+/// Row groups, and pages within them, whose statistics for the column
+/// `index_name` may overlap `[lo, hi)`. Missing statistics never prune.
+fn select_time_window(
+    metadata: &ParquetMetaData,
+    arrow_schema: &Schema,
+    parquet_schema: &SchemaDescriptor,
+    index_name: &str,
+    lo: i64,
+    hi: i64,
+) -> Result<(Vec<usize>, RowSelection), DsError> {
+    let converter = StatisticsConverter::try_new(index_name, arrow_schema, parquet_schema)?;
+    let row_group_metas = metadata.row_groups();
+
+    let mins = cast(
+        &converter.row_group_mins(row_group_metas)?,
+        &DataType::Int64,
+    )?;
+    let maxes = cast(
+        &converter.row_group_maxes(row_group_metas)?,
+        &DataType::Int64,
+    )?;
+    let (mins, maxes) = (
+        mins.as_primitive::<Int64Type>(),
+        maxes.as_primitive::<Int64Type>(),
+    );
+    let row_groups: Vec<usize> = (0..row_group_metas.len())
+        .filter(|&i| may_overlap(mins, maxes, i, lo, hi))
+        .collect();
+
+    // Refine to data pages when the file has a page index
+    if let (Some(column_index), Some(offset_index)) =
+        (metadata.column_index(), metadata.offset_index())
+    {
+        let page_counts =
+            converter.data_page_row_counts(offset_index, row_group_metas, &row_groups)?;
+        if let Some(page_counts) = page_counts {
+            let mins = converter.data_page_mins(column_index, offset_index, &row_groups)?;
+            let maxes = converter.data_page_maxes(column_index, offset_index, &row_groups)?;
+            let mins = cast(&mins, &DataType::Int64)?;
+            let maxes = cast(&maxes, &DataType::Int64)?;
+            let (mins, maxes) = (
+                mins.as_primitive::<Int64Type>(),
+                maxes.as_primitive::<Int64Type>(),
+            );
+
+            let selectors: Vec<RowSelector> = page_counts
+                .iter()
+                .enumerate()
+                .map(|(i, count)| {
+                    let count = count.unwrap_or(0) as usize;
+                    if may_overlap(mins, maxes, i, lo, hi) {
+                        RowSelector::select(count)
+                    } else {
+                        RowSelector::skip(count)
+                    }
+                })
+                .collect();
+            return Ok((row_groups, RowSelection::from(selectors)));
+        }
+    }
+
+    let selected: usize = row_groups
+        .iter()
+        .map(|&i| row_group_metas[i].num_rows() as usize)
+        .sum();
+    Ok((
+        row_groups,
+        RowSelection::from(vec![RowSelector::select(selected)]),
+    ))
+}
+
+/// Whether chunk `i` with the given min/max statistics may hold values in `[lo, hi)`
+fn may_overlap(mins: &Int64Array, maxes: &Int64Array, i: usize, lo: i64, hi: i64) -> bool {
+    (mins.is_null(i) || mins.value(i) < hi) && (maxes.is_null(i) || maxes.value(i) >= lo)
+}
+
+/// Convert microseconds to `unit`, rounding up so that `[start, end)` bounds stay exact
+fn micros_to_unit_ceil(micros: i64, unit: TimeUnit) -> i64 {
+    let div_ceil = |v: i64, d: i64| v.div_euclid(d) + i64::from(v.rem_euclid(d) != 0);
+    match unit {
+        TimeUnit::Second => div_ceil(micros, 1_000_000),
+        TimeUnit::Millisecond => div_ceil(micros, 1_000),
+        TimeUnit::Microsecond => micros,
+        TimeUnit::Nanosecond => micros.saturating_mul(1_000),
+    }
+}
+
+fn unit_to_micros(value: i64, unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => value.saturating_mul(1_000_000),
+        TimeUnit::Millisecond => value.saturating_mul(1_000),
+        TimeUnit::Microsecond => value,
+        TimeUnit::Nanosecond => value.div_euclid(1_000),
+    }
+}
+
+/// Row groups overlapping `rows`, and the selection of `rows` within those groups
+fn select_row_range(metadata: &ParquetMetaData, rows: &Range<usize>) -> (Vec<usize>, RowSelection) {
+    let mut row_groups = Vec::new();
+    let mut selectors = Vec::new();
+    let mut group_start = 0usize;
+
+    for (i, row_group) in metadata.row_groups().iter().enumerate() {
+        let group_end = group_start + row_group.num_rows() as usize;
+        if group_start < rows.end && rows.start < group_end {
+            let lo = rows.start.max(group_start);
+            let hi = rows.end.min(group_end);
+            row_groups.push(i);
+            if lo > group_start {
+                selectors.push(RowSelector::skip(lo - group_start));
+            }
+            selectors.push(RowSelector::select(hi - lo));
+            if group_end > hi {
+                selectors.push(RowSelector::skip(group_end - hi));
+            }
+        }
+        group_start = group_end;
+    }
+
+    (row_groups, RowSelection::from(selectors))
+}
+
+fn type_mismatch(column_name: &str, expected: &str, found: &DataType) -> DsError {
+    DsError::TypeMismatch {
+        column: column_name.to_string(),
+        expected: expected.to_string(),
+        found: format!("{:?}", found),
+    }
+}
+
+/// Flatten Float64 arrays into Vec<f64>, replacing NULL with NaN
+fn f64_values(arrays: &[ArrayRef], capacity: usize) -> Vec<f64> {
+    let mut values: Vec<f64> = Vec::with_capacity(capacity);
+    for array in arrays {
+        let col = array.as_primitive::<Float64Type>();
+        for opt in col.iter() {
+            values.push(opt.unwrap_or(f64::NAN));
+        }
+    }
+    values
+}
+
+/// Convert the arrays of `field`, one per record batch, to a column of its
+/// native type; nullable fields give the nullable variants
+fn arrays_column(field: &Field, arrays: &[ArrayRef]) -> Result<Column, DsError> {
+    fn column<T>(
+        values: Vec<Option<T>>,
+        nullable: bool,
+        plain: impl FnOnce(Vec<T>) -> Column,
+        with_nulls: impl FnOnce(Vec<Option<T>>) -> Column,
+    ) -> Column {
+        if nullable {
+            with_nulls(values)
+        } else {
+            // Not nullable, so there is no NULL to drop
+            plain(values.into_iter().flatten().collect())
+        }
+    }
+
+    let nullable = field.is_nullable();
+    match field.data_type() {
+        DataType::Int64 => {
+            let values = arrays
+                .iter()
+                .flat_map(|a| a.as_primitive::<Int64Type>().iter())
+                .collect();
+            Ok(column(
+                values,
+                nullable,
+                Column::Int64,
+                Column::NullableInt64,
+            ))
+        }
+        DataType::Float64 => {
+            let values = arrays
+                .iter()
+                .flat_map(|a| a.as_primitive::<Float64Type>().iter())
+                .collect();
+            Ok(column(
+                values,
+                nullable,
+                Column::Float64,
+                Column::NullableFloat64,
+            ))
+        }
+        DataType::Boolean => {
+            let values = arrays.iter().flat_map(|a| a.as_boolean().iter()).collect();
+            Ok(column(
+                values,
+                nullable,
+                Column::Boolean,
+                Column::NullableBoolean,
+            ))
+        }
+        DataType::Timestamp(unit, _) => {
+            let mut values = Vec::new();
+            for array in arrays {
+                let col = cast(array, &DataType::Int64)?;
+                values.extend(col.as_primitive::<Int64Type>().iter());
+            }
+            let unit = *unit;
+            Ok(column(
+                values,
+                nullable,
+                |v| Column::Timestamp(unit, v),
+                |v| Column::NullableTimestamp(unit, v),
+            ))
+        }
+        t if is_string_type(t) => {
+            let mut values = Vec::new();
+            for array in arrays {
+                // Normalise LargeUtf8, Utf8View and dictionaries to plain Utf8
+                let col = cast(array, &DataType::Utf8)?;
+                values.extend(col.as_string::<i32>().iter().map(|s| s.map(str::to_string)));
+            }
+            Ok(column(values, nullable, Column::Utf8, Column::NullableUtf8))
+        }
+        other => Err(DsError::Unsupported(format!(
+            "reading column '{}' of type {:?}",
+            field.name(),
+            other
+        ))),
+    }
+}
+
+/// Values of a statistics array as scalars; None for types without a `Scalar` form
+fn stat_scalars(array: &ArrayRef) -> Result<Option<Vec<Option<Scalar>>>, DsError> {
+    let values = match array.data_type() {
+        DataType::Int64 => array
+            .as_primitive::<Int64Type>()
+            .iter()
+            .map(|v| v.map(Scalar::Int64))
+            .collect(),
+        DataType::Float64 => array
+            .as_primitive::<Float64Type>()
+            .iter()
+            .map(|v| v.map(Scalar::Float64))
+            .collect(),
+        DataType::Timestamp(unit, _) => cast(array, &DataType::Int64)?
+            .as_primitive::<Int64Type>()
+            .iter()
+            .map(|v| v.map(|t| Scalar::Timestamp(*unit, t)))
+            .collect(),
+        DataType::Boolean => array
+            .as_boolean()
+            .iter()
+            .map(|v| v.map(Scalar::Boolean))
+            .collect(),
+        t if is_string_type(t) => cast(array, &DataType::Utf8)?
+            .as_string::<i32>()
+            .iter()
+            .map(|v| v.map(|s| Scalar::Utf8(s.to_string())))
+            .collect(),
+        _ => return Ok(None),
+    };
+    Ok(Some(values))
+}
+
+/// Utf8, LargeUtf8, Utf8View or a dictionary with one of those as values
+fn is_string_type(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => true,
+        DataType::Dictionary(_, value_type) => is_string_type(value_type),
+        _ => false,
+    }
 }
 
 impl DataFrame for ArrowDataFrame {
     fn shape(&self) -> (usize, usize) {
-        (self.row_count, self.schema.fields().len())
+        // Stored index levels are not columns, as in `column_names`
+        (self.row_count, self.column_names().len())
     }
 
     fn column_names(&self) -> Vec<String> {
+        let index_fields: HashSet<&str> = self
+            .index_levels
+            .iter()
+            .filter_map(|level| match level {
+                IndexLevel::Stored { field, .. } => Some(field.as_str()),
+                IndexLevel::Range { .. } => None,
+            })
+            .collect();
         self.schema
             .fields()
             .iter()
-            .filter(|f| f.name() != INDEX_NAME)
+            .filter(|f| !index_fields.contains(f.name().as_str()))
             .map(|f| f.name().to_string())
             .collect()
     }
 
     fn column_types(&self) -> HashMap<String, String> {
-        self.schema
+        let mut types: HashMap<String, String> = self
+            .schema
             .fields()
             .iter()
             .map(|f| (f.name().to_string(), format!("{:?}", f.data_type())))
+            .collect();
+        for level in &self.index_levels {
+            if let IndexLevel::Range { name, .. } = level {
+                types.insert(name.clone(), format!("{:?}", DataType::Int64));
+            }
+        }
+        types
+    }
+
+    fn has_index(&self) -> bool {
+        self.time_index_field().is_some()
+    }
+
+    fn index_names(&self) -> Vec<String> {
+        self.index_levels
+            .iter()
+            .map(|level| level.name().to_string())
             .collect()
     }
 
-    fn read_column_string(&self, _column_name: &str) -> Vec<String> {
-        panic!("Not implemented.");
+    fn index_fields(&self) -> Vec<(String, String)> {
+        self.index_levels
+            .iter()
+            .filter_map(|level| match level {
+                IndexLevel::Stored { name, field } => Some((field.clone(), name.clone())),
+                IndexLevel::Range { .. } => None,
+            })
+            .collect()
+    }
+
+    fn column_summary(&self, column_name: &str) -> Result<ColumnSummary, DsError> {
+        if self.schema.index_of(column_name).is_ok()
+            && let Some(summary) = self.footer_summary(column_name)?
+        {
+            return Ok(summary);
+        }
+        Ok(ColumnSummary::scan(&self.read_column(column_name)?))
+    }
+
+    fn read_column(&self, column_name: &str) -> Result<Column, DsError> {
+        if let Some(values) = self.range_index(column_name, &(0..self.row_count)) {
+            return Ok(Column::Int64(values));
+        }
+        let (idx, _) = self.field(column_name)?;
+        let arrays = self.read_column_arrays(idx, 0..self.row_count)?;
+        arrays_column(self.schema.field(idx), &arrays)
+    }
+
+    fn read_column_string(&self, column_name: &str) -> Result<Vec<String>, DsError> {
+        self.read_column_string_range(column_name, 0..self.row_count)
     }
 
-    fn read_column_i64(&self, column_name: &str) -> Vec<i64> {
-        use arrow::array::Int64Array;
+    fn read_column_string_opt(&self, column_name: &str) -> Result<Vec<Option<String>>, DsError> {
+        self.read_column_string_opt_range(column_name, 0..self.row_count)
+    }
 
-        let file = File::open(&self.path)
-            .unwrap_or_else(|e| panic!("failed to open parquet file '{}': {e}", self.path));
-        let builder = ParquetRecordBatchReaderBuilder::try_new(file)
-            .unwrap_or_else(|e| panic!("failed to build parquet reader: {e}"));
-        let mut reader = builder
-            .build()
-            .unwrap_or_else(|e| panic!("failed to build record batch reader: {e}"));
+    fn read_column_i64(&self, column_name: &str) -> Result<Vec<i64>, DsError> {
+        self.read_column_i64_range(column_name, 0..self.row_count)
+    }
 
-        let mut values: Vec<i64> = Vec::with_capacity(self.row_count);
+    fn read_column_f64(&self, column_name: &str) -> Result<Vec<f64>, DsError> {
+        self.read_column_f64_range(column_name, 0..self.row_count)
+    }
+
+    fn read_columns_f64(&self, column_names: &[&str]) -> Result<Vec<Vec<f64>>, DsError> {
+        self.read_columns_f64_rows(column_names, 0..self.row_count)
+    }
 
-        while let Some(batch_res) = reader.next() {
-            let batch = batch_res.unwrap_or_else(|e| panic!("error reading batch: {e}"));
+    fn read_index_microsecond(&self) -> Result<Vec<i64>, DsError> {
+        self.read_index_microsecond_range(0..self.row_count)
+    }
 
-            let idx = batch
-                .schema()
-                .index_of(column_name)
-                .unwrap_or_else(|_| panic!("column '{}' not found in batch", column_name));
+    fn read_column_string_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<String>, DsError> {
+        let start = rows.start;
+        self.read_column_string_opt_range(column_name, rows)?
+            .into_iter()
+            .enumerate()
+            .map(|(k, opt)| {
+                opt.ok_or_else(|| DsError::NullInNonNullable {
+                    column: column_name.to_string(),
+                    row: start + k,
+                })
+            })
+            .collect()
+    }
 
-            let col = batch
-                .column(idx)
-                .as_any()
-                .downcast_ref::<Int64Array>()
-                .unwrap_or_else(|| panic!("column '{}' is not Int64", column_name));
+    fn read_column_string_opt_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<Option<String>>, DsError> {
+        let (idx, data_type) = self.field(column_name)?;
+        if !is_string_type(data_type) {
+            return Err(type_mismatch(column_name, "Utf8", data_type));
+        }
 
-            for opt in col.iter() {
-                values.push(opt.unwrap_or(0));
+        let mut values: Vec<Option<String>> = Vec::with_capacity(rows.len());
+        for array in self.read_column_arrays(idx, rows)? {
+            // Normalise LargeUtf8, Utf8View and dictionaries to plain Utf8
+            let col = cast(&array, &DataType::Utf8)?;
+            for opt in col.as_string::<i32>().iter() {
+                values.push(opt.map(str::to_string));
             }
         }
+        Ok(values)
+    }
+
+    fn read_column_i64_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<i64>, DsError> {
+        check_row_range(&rows, self.row_count)?;
+        if let Some(values) = self.range_index(column_name, &rows) {
+            return Ok(values);
+        }
+        let (idx, data_type) = self.field(column_name)?;
+        if *data_type != DataType::Int64 {
+            return Err(type_mismatch(column_name, "Int64", data_type));
+        }
+
+        Ok(self
+            .read_primitive_opt::<Int64Type>(idx, rows)?
+            .into_iter()
+            .map(|opt| opt.unwrap_or(0))
+            .collect())
+    }
 
-        values
+    fn read_column_f64_range(
+        &self,
+        column_name: &str,
+        rows: Range<usize>,
+    ) -> Result<Vec<f64>, DsError> {
+        Ok(self.read_columns_f64_rows(&[column_name], rows)?.remove(0))
+    }
+
+    fn read_column_timestamp_range(
+        &self,
+        column_name: &str,
+        unit: TimeUnit,
+        rows: Range<usize>,
+    ) -> Result<Vec<i64>, DsError> {
+        let (idx, data_type) = self.field(column_name)?;
+        // Unit the stored values count once cast to Int64
+        let from = match data_type {
+            DataType::Timestamp(from, _) => *from,
+            DataType::Date32 => TimeUnit::Second,
+            DataType::Date64 => TimeUnit::Millisecond,
+            DataType::Int64 => unit,
+            other => return Err(type_mismatch(column_name, "Timestamp", other)),
+        };
+
+        let start = rows.start;
+        let mut values: Vec<Option<i64>> = Vec::with_capacity(rows.len());
+        for array in self.read_column_arrays(idx, rows)? {
+            let col = cast(&array, &DataType::Int64)?;
+            let col = col.as_primitive::<Int64Type>().iter();
+            if *data_type == DataType::Date32 {
+                values.extend(col.map(|days| days.map(|d| d * 86_400)));
+            } else {
+                values.extend(col);
+            }
+        }
+        convert_timestamps(column_name, start, values, from, unit)
     }
 
-    fn read_column_f64(&self, column_name: &str) -> Vec<f64> {
-        let file = File::open(&self.path)
-            .unwrap_or_else(|e| panic!("failed to open parquet file '{}': {e}", self.path));
-        let builder = ParquetRecordBatchReaderBuilder::try_new(file)
-            .unwrap_or_else(|e| panic!("failed to build parquet reader: {e}"));
-        let mut reader = builder
-            .build()
-            .unwrap_or_else(|e| panic!("failed to build record batch reader: {e}"));
+    fn read_index_microsecond_range(&self, rows: Range<usize>) -> Result<Vec<i64>, DsError> {
+        let Some(field) = self.time_index_field() else {
+            return Err(DsError::Unsupported(format!(
+                "'{}' has no timestamp index",
+                self.path
+            )));
+        };
+        self.read_column_timestamp_range(field, TimeUnit::Microsecond, rows)
+    }
 
-        let mut values: Vec<f64> = Vec::with_capacity(self.row_count);
+    fn read_window(
+        &self,
+        start: i64,
+        end: i64,
+        column_names: &[&str],
+    ) -> Result<(Vec<i64>, Vec<Column>), DsError> {
+        let (index_idx, unit) = self.time_index()?;
+        let index_name = self.schema.field(index_idx).name();
+        let (lo, hi) = (
+            micros_to_unit_ceil(start, unit),
+            micros_to_unit_ceil(end, unit),
+        );
 
-        while let Some(batch_res) = reader.next() {
-            let batch = batch_res.unwrap_or_else(|e| panic!("error reading batch: {e}"));
+        let mut indices = vec![index_idx];
+        for &column_name in column_names {
+            indices.push(self.field(column_name)?.0);
+        }
 
-            let idx = batch
-                .schema()
-                .index_of(column_name)
-                .unwrap_or_else(|_| panic!("column '{}' not found in batch", column_name));
+        let builder = self.open_builder(true)?;
+        let plan = select_time_window(
+            builder.metadata(),
+            &self.schema,
+            builder.parquet_schema(),
+            index_name,
+            lo,
+            hi,
+        )?;
+        let mut arrays = self.read_arrays_from(builder, &indices, Some(plan))?;
 
-            let col = batch
-                .column(idx)
-                .as_any()
-                .downcast_ref::<Float64Array>()
-                .unwrap_or_else(|| panic!("column '{}' is not Float64", column_name));
+        // Statistics only prune whole chunks; filter the remaining rows exactly
+        let index_arrays = arrays.remove(0);
+        let mut index = Vec::new();
+        let mut filtered: Vec<Vec<ArrayRef>> = vec![Vec::new(); column_names.len()];
+        for (b, index_array) in index_arrays.iter().enumerate() {
+            let ts = cast(index_array, &DataType::Int64)?;
+            let ts = ts.as_primitive::<Int64Type>();
+            let in_window: BooleanArray = ts
+                .iter()
+                .map(|opt| Some(opt.is_some_and(|t| lo <= t && t < hi)))
+                .collect();
+            let kept = filter(ts, &in_window)?;
+            index.extend(
+                kept.as_primitive::<Int64Type>()
+                    .values()
+                    .iter()
+                    .map(|&t| unit_to_micros(t, unit)),
+            );
+            for (k, column_arrays) in arrays.iter().enumerate() {
+                filtered[k].push(filter(&column_arrays[b], &in_window)?);
+            }
+        }
+        let columns = filtered
+            .iter()
+            .zip(&indices[1..])
+            .map(|(arrays, &idx)| arrays_column(self.schema.field(idx), arrays))
+            .collect::<Result<_, _>>()?;
+        Ok((index, columns))
+    }
 
-            for opt in col.iter() {
-                values.push(opt.unwrap_or(f64::NAN));
+    fn read_window_f64(
+        &self,
+        start: i64,
+        end: i64,
+        column_names: &[&str],
+    ) -> Result<(Vec<i64>, Vec<Vec<f64>>), DsError> {
+        for &column_name in column_names {
+            let (_, data_type) = self.field(column_name)?;
+            if *data_type != DataType::Float64 {
+                return Err(type_mismatch(column_name, "Float64", data_type));
             }
         }
+        let (index, columns) = self.read_window(start, end, column_names)?;
+        let columns = columns
+            .into_iter()
+            .zip(column_names)
+            .map(|(column, column_name)| match column {
+                Column::Float64(v) => Ok(v),
+                Column::NullableFloat64(v) => {
+                    Ok(v.into_iter().map(|x| x.unwrap_or(f64::NAN)).collect())
+                }
+                other => Err(type_mismatch(column_name, "Float64", &other.data_type())),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((index, columns))
+    }
+}
+
+/// Options for writing Parquet files
+#[derive(Debug, Clone)]
+pub struct ParquetWriteOptions {
+    /// Write the frame index, if any: index levels stored as columns keep
+    /// their fields and types, another time index becomes `__index_level_0__`
+    pub write_index: bool,
+    /// Sort data columns alphanumerically instead of keeping the given order
+    pub sort_columns: bool,
+    /// Write NaN in Float64 columns as NULL, making those columns nullable
+    pub nan_as_null: bool,
+    /// Compression codec and level, e.g. `Compression::ZSTD(ZstdLevel::try_new(3)?)`
+    pub compression: Compression,
+    /// Maximum number of rows per row group; must be greater than zero
+    pub max_row_group_size: usize,
+    /// Target size of a data page in bytes
+    pub data_page_size: usize,
+    /// Dictionary encoding for all columns, unless overridden in `dictionary_columns`
+    pub dictionary: bool,
+    /// Per-column dictionary encoding overrides
+    pub dictionary_columns: HashMap<String, bool>,
+    /// Level of min/max/null count statistics: none, per chunk, or per page
+    pub statistics: EnabledStatistics,
+    /// Columns to write bloom filters for
+    pub bloom_filter_columns: Vec<String>,
+    /// Value of the `created_by` field in the file footer
+    pub created_by: Option<String>,
+    /// Write `pandas` schema metadata so pandas restores the frame index, or
+    /// else `__index_level_0__` or the `timestamp` column, as its index
+    pub pandas_metadata: bool,
+}
+
+impl Default for ParquetWriteOptions {
+    fn default() -> Self {
+        Self {
+            write_index: true,
+            sort_columns: false,
+            nan_as_null: false,
+            compression: DEFAULT_COMPRESSION,
+            max_row_group_size: DEFAULT_MAX_ROW_GROUP_SIZE,
+            data_page_size: DEFAULT_PAGE_SIZE,
+            dictionary: DEFAULT_DICTIONARY_ENABLED,
+            dictionary_columns: HashMap::new(),
+            statistics: DEFAULT_STATISTICS_ENABLED,
+            bloom_filter_columns: Vec::new(),
+            created_by: None,
+            pandas_metadata: false,
+        }
+    }
+}
 
-        values
+impl ParquetWriteOptions {
+    fn writer_properties(&self) -> Result<WriterProperties, DsError> {
+        if self.max_row_group_size == 0 {
+            return Err(DsError::InvalidArgument(
+                "max_row_group_size must be greater than zero".to_string(),
+            ));
+        }
+        let mut builder = WriterProperties::builder()
+            .set_compression(self.compression)
+            .set_max_row_group_size(self.max_row_group_size)
+            .set_data_page_size_limit(self.data_page_size)
+            .set_dictionary_enabled(self.dictionary)
+            .set_statistics_enabled(self.statistics);
+        for (name, enabled) in &self.dictionary_columns {
+            builder =
+                builder.set_column_dictionary_enabled(ColumnPath::from(name.as_str()), *enabled);
+        }
+        for name in &self.bloom_filter_columns {
+            builder =
+                builder.set_column_bloom_filter_enabled(ColumnPath::from(name.as_str()), true);
+        }
+        if let Some(created_by) = &self.created_by {
+            builder = builder.set_created_by(created_by.clone());
+        }
+        Ok(builder.build())
     }
+}
 
-    fn read_index_microsecond(&self) -> Vec<i64> {
-        use arrow::array::TimestampMicrosecondArray;
-        use arrow::datatypes::{DataType, TimeUnit};
+/// Write a `timestamp` column (seconds, UTC) followed by the data columns
+/// sorted alphanumerically by name
+pub fn write_parquet(
+    file_path: &str,
+    timestamps: Option<Vec<i64>>,
+    int_data: Option<HashMap<String, Vec<i64>>>,
+    float_data: Option<HashMap<String, Vec<f64>>>,
+    string_data: Option<HashMap<String, Vec<String>>>,
+    options: &ParquetWriteOptions,
+) -> Result<(), DsError> {
+    let mut columns: Vec<(String, Column)> = Vec::new();
 
-        let file = File::open(&self.path)
-            .unwrap_or_else(|e| panic!("failed to open parquet file '{}': {e}", self.path));
+    // Add timestamp column only if provided
+    if let Some(ts) = timestamps {
+        columns.push((
+            "timestamp".to_string(),
+            Column::Timestamp(TimeUnit::Second, ts),
+        ));
+    }
 
-        // No need for `mut` here either
-        let builder = ParquetRecordBatchReaderBuilder::try_new(file)
-            .unwrap_or_else(|e| panic!("failed to build parquet reader: {e}"));
-        let mut reader = builder
-            .build()
-            .unwrap_or_else(|e| panic!("failed to build record batch reader: {e}"));
+    // Build a unified list of (name, column) pairs
+    let mut data_columns: Vec<(String, Column)> = Vec::new();
+    for (name, data) in int_data.unwrap_or_default() {
+        data_columns.push((name, Column::Int64(data)));
+    }
+    for (name, data) in float_data.unwrap_or_default() {
+        data_columns.push((name, Column::Float64(data)));
+    }
+    for (name, data) in string_data.unwrap_or_default() {
+        data_columns.push((name, Column::Utf8(data)));
+    }
 
-        let mut values: Vec<i64> = Vec::with_capacity(self.row_count);
-        let mut global_row: usize = 0;
+    // Sort alphanumerically by column name
+    sort_columns(&mut data_columns);
+    columns.extend(data_columns);
 
-        while let Some(batch_res) = reader.next() {
-            let batch = batch_res.unwrap_or_else(|e| panic!("error reading batch: {e}"));
+    write_columns(file_path, columns, &[], options)
+}
 
-            // FIX: hold the schema in a local binding so the &Field borrow is valid
-            let schema = batch.schema();
-            let idx = schema
-                .index_of(INDEX_NAME)
-                .unwrap_or_else(|_| panic!("index column '{}' not found", INDEX_NAME));
+/// Write columns to a Parquet file; the schema follows the order of `columns`
+/// unless `options.sort_columns` is set
+pub fn write_parquet_columns(
+    file_path: &str,
+    mut columns: Vec<(String, Column)>,
+    options: &ParquetWriteOptions,
+) -> Result<(), DsError> {
+    if options.sort_columns {
+        sort_columns(&mut columns);
+    }
+    write_columns(file_path, columns, &[], options)
+}
 
-            // Validate dtype once per batch
-            let field = schema.field(idx);
-            match field.data_type() {
-                DataType::Timestamp(TimeUnit::Microsecond, tz) if tz.as_deref() == Some("UTC") => {}
-                other => panic!(
-                    "index column '{}' has dtype {:?}, expected Timestamp(Microsecond, Some(\"UTC\"))",
-                    INDEX_NAME, other
-                ),
-            }
+/// Write any DataFrame to a Parquet file, keeping each column's native type
+/// and the frame's column order. Index levels stored as columns are written
+/// first under their own field names and types, and recorded in `pandas`
+/// metadata unless the index is the lone `__index_level_0__` column, which is
+/// recognised without it; RangeIndex levels are not written.
+pub fn write_parquet_dataframe(
+    file_path: &str,
+    df: &dyn DataFrame,
+    options: &ParquetWriteOptions,
+) -> Result<(), DsError> {
+    let columns = dataframe_columns(df, options)?;
+    write_columns(file_path, columns, &written_index(df, options), options)
+}
 
-            let col = batch
-                .column(idx)
-                .as_any()
-                .downcast_ref::<TimestampMicrosecondArray>()
-                .unwrap_or_else(|| {
-                    panic!(
-                        "index column '{}' is not a TimestampMicrosecondArray",
-                        INDEX_NAME
-                    )
-                });
+/// The index levels `write_parquet_dataframe` writes for `df`, as
+/// `(field, level name)` pairs: the stored levels, else a time index as a
+/// microsecond `__index_level_0__` column
+pub(crate) fn written_index(
+    df: &dyn DataFrame,
+    options: &ParquetWriteOptions,
+) -> Vec<(String, String)> {
+    if !options.write_index {
+        return Vec::new();
+    }
+    let fields = df.index_fields();
+    if fields.is_empty() && df.has_index() {
+        vec![(INDEX_NAME.to_string(), INDEX_NAME.to_string())]
+    } else {
+        fields
+    }
+}
 
-            for (j, opt) in col.iter().enumerate() {
-                let ts = opt.unwrap_or_else(|| {
-                    panic!(
-                        "index column '{}' contains a NULL at row {}",
-                        INDEX_NAME,
-                        global_row + j
-                    )
-                });
-                values.push(ts);
+/// The columns `write_parquet_dataframe` writes for `df`: the index levels
+/// first if requested, then the data columns
+pub(crate) fn dataframe_columns(
+    df: &dyn DataFrame,
+    options: &ParquetWriteOptions,
+) -> Result<Vec<(String, Column)>, DsError> {
+    let stored = !df.index_fields().is_empty();
+    let mut columns: Vec<(String, Column)> = Vec::new();
+    for (field, _) in written_index(df, options) {
+        let column = if stored {
+            df.read_column(&field)?
+        } else {
+            Column::Timestamp(TimeUnit::Microsecond, df.read_index_microsecond()?)
+        };
+        columns.push((field, column));
+    }
+    let mut data_columns: Vec<(String, Column)> = Vec::new();
+    for name in df.column_names() {
+        let column = df.read_column(&name)?;
+        data_columns.push((name, column));
+    }
+    if options.sort_columns {
+        sort_columns(&mut data_columns);
+    }
+    columns.extend(data_columns);
+    Ok(columns)
+}
+
+fn sort_columns(columns: &mut [(String, Column)]) {
+    columns.sort_by(|a, b| alphanumeric_sort::compare_str(&a.0, &b.0));
+}
+
+pub(crate) fn column_array(column: Column, nan_as_null: bool) -> ArrayRef {
+    match column {
+        Column::Int64(v) => Arc::new(Int64Array::from(v)),
+        Column::Float64(v) if nan_as_null => Arc::new(
+            v.into_iter()
+                .map(|x| (!x.is_nan()).then_some(x))
+                .collect::<Float64Array>(),
+        ),
+        Column::Float64(v) => Arc::new(Float64Array::from(v)),
+        Column::Utf8(v) => Arc::new(StringArray::from(v)),
+        Column::Boolean(v) => Arc::new(BooleanArray::from(v)),
+        Column::NullableInt64(v) => Arc::new(Int64Array::from(v)),
+        Column::NullableFloat64(v) if nan_as_null => Arc::new(
+            v.into_iter()
+                .map(|opt| opt.filter(|x| !x.is_nan()))
+                .collect::<Float64Array>(),
+        ),
+        Column::NullableFloat64(v) => Arc::new(Float64Array::from(v)),
+        Column::NullableUtf8(v) => Arc::new(StringArray::from(v)),
+        Column::NullableBoolean(v) => Arc::new(BooleanArray::from(v)),
+        Column::Timestamp(TimeUnit::Second, v) => {
+            Arc::new(TimestampSecondArray::from(v).with_timezone("UTC"))
+        }
+        Column::NullableTimestamp(TimeUnit::Second, v) => {
+            Arc::new(TimestampSecondArray::from(v).with_timezone("UTC"))
+        }
+        Column::Timestamp(TimeUnit::Millisecond, v) => {
+            Arc::new(TimestampMillisecondArray::from(v).with_timezone("UTC"))
+        }
+        Column::NullableTimestamp(TimeUnit::Millisecond, v) => {
+            Arc::new(TimestampMillisecondArray::from(v).with_timezone("UTC"))
+        }
+        Column::Timestamp(TimeUnit::Microsecond, v) => {
+            Arc::new(TimestampMicrosecondArray::from(v).with_timezone("UTC"))
+        }
+        Column::NullableTimestamp(TimeUnit::Microsecond, v) => {
+            Arc::new(TimestampMicrosecondArray::from(v).with_timezone("UTC"))
+        }
+        Column::Timestamp(TimeUnit::Nanosecond, v) => {
+            Arc::new(TimestampNanosecondArray::from(v).with_timezone("UTC"))
+        }
+        Column::NullableTimestamp(TimeUnit::Nanosecond, v) => {
+            Arc::new(TimestampNanosecondArray::from(v).with_timezone("UTC"))
+        }
+    }
+}
+
+/// The `pandas` metadata JSON describing `schema`, as pyarrow writes it. The
+/// index is given as `(field, level name)` pairs; if none are given it is
+/// `__index_level_0__`, else a `timestamp` column, else a default RangeIndex.
+fn pandas_metadata(schema: &Schema, index: &[(String, String)]) -> String {
+    let index: Vec<(&str, &str)> = if index.is_empty() {
+        [INDEX_NAME, "timestamp"]
+            .into_iter()
+            .find(|name| {
+                schema
+                    .field_with_name(name)
+                    .is_ok_and(|f| matches!(f.data_type(), DataType::Timestamp(..)))
+            })
+            .map(|name| (name, name))
+            .into_iter()
+            .collect()
+    } else {
+        index
+            .iter()
+            .map(|(field, name)| (field.as_str(), name.as_str()))
+            .collect()
+    };
+
+    let columns: Vec<Value> = schema
+        .fields()
+        .iter()
+        .map(|f| {
+            let (pandas_type, numpy_type, metadata) = match f.data_type() {
+                // The nullable extension dtype keeps NULL apart from 0
+                DataType::Int64 if f.is_nullable() => ("int64", "Int64".to_string(), Value::Null),
+                DataType::Int64 => ("int64", "int64".to_string(), Value::Null),
+                DataType::Float64 => ("float64", "float64".to_string(), Value::Null),
+                DataType::Boolean if f.is_nullable() => {
+                    ("bool", "boolean".to_string(), Value::Null)
+                }
+                DataType::Boolean => ("bool", "bool".to_string(), Value::Null),
+                DataType::Timestamp(unit, tz) => {
+                    let unit = match unit {
+                        TimeUnit::Second => "s",
+                        TimeUnit::Millisecond => "ms",
+                        TimeUnit::Microsecond => "us",
+                        TimeUnit::Nanosecond => "ns",
+                    };
+                    let numpy_type = format!("datetime64[{}]", unit);
+                    match tz {
+                        Some(tz) => ("datetimetz", numpy_type, json!({ "timezone": tz.as_ref() })),
+                        None => ("datetime", numpy_type, Value::Null),
+                    }
+                }
+                _ => ("unicode", "object".to_string(), Value::Null),
+            };
+            // Unnamed index levels are null in pandas, their fields `__index_level_N__`
+            let name = match index.iter().find(|(field, _)| field == f.name()) {
+                Some((_, name)) if name.starts_with("__index_level_") => Value::Null,
+                Some((_, name)) => json!(name),
+                None => json!(f.name()),
+            };
+            json!({
+                "name": name,
+                "field_name": f.name(),
+                "pandas_type": pandas_type,
+                "numpy_type": numpy_type,
+                "metadata": metadata,
+            })
+        })
+        .collect();
+
+    json!({
+        "index_columns": index.iter().map(|(field, _)| field).collect::<Vec<_>>(),
+        "column_indexes": [{
+            "name": null,
+            "field_name": null,
+            "pandas_type": "unicode",
+            "numpy_type": "object",
+            "metadata": { "encoding": "UTF-8" },
+        }],
+        "columns": columns,
+        "creator": { "library": "ds_util", "version": env!("CARGO_PKG_VERSION") },
+    })
+    .to_string()
+}
+
+/// Arrow schema for writing `columns` in the given order
+fn columns_schema(columns: &[(String, Column)], options: &ParquetWriteOptions) -> SchemaRef {
+    let fields: Vec<Field> = columns
+        .iter()
+        .map(|(name, col)| {
+            let nullable =
+                col.is_nullable() || (options.nan_as_null && col.data_type() == DataType::Float64);
+            Field::new(name.as_str(), col.data_type(), nullable)
+        })
+        .collect();
+    Arc::new(Schema::new(fields))
+}
+
+/// Write columns to a Parquet file in the given order
+fn write_columns(
+    file_path: &str,
+    columns: Vec<(String, Column)>,
+    index: &[(String, String)],
+    options: &ParquetWriteOptions,
+) -> Result<(), DsError> {
+    let schema = columns_schema(&columns, options);
+
+    let mut writer = ParquetStreamWriter::create(file_path, schema, index, options)?;
+    writer.write_batch(columns.into_iter().map(|(_, col)| col).collect())?;
+    writer.finish()
+}
+
+/// Writes a Parquet file incrementally, one batch of rows at a time, so that
+/// at most one row group is held in memory
+pub struct ParquetStreamWriter {
+    writer: ArrowWriter<File>,
+    schema: SchemaRef,
+    nan_as_null: bool,
+}
+
+impl ParquetStreamWriter {
+    /// Create the file at `file_path`; every batch must match `schema`
+    pub fn open(
+        file_path: &str,
+        schema: SchemaRef,
+        options: &ParquetWriteOptions,
+    ) -> Result<Self, DsError> {
+        Self::create(file_path, schema, &[], options)
+    }
+
+    /// Create the file, recording the `(field, level name)` index levels in
+    /// `pandas` metadata when pandas could not tell them otherwise
+    fn create(
+        file_path: &str,
+        schema: SchemaRef,
+        index: &[(String, String)],
+        options: &ParquetWriteOptions,
+    ) -> Result<Self, DsError> {
+        let mut seen = HashSet::new();
+        for field in schema.fields() {
+            if !seen.insert(field.name().as_str()) {
+                return Err(DsError::InvalidArgument(format!(
+                    "duplicate column name '{}'",
+                    field.name()
+                )));
             }
+        }
 
-            global_row += col.len();
+        let props = options.writer_properties()?;
+        let file = File::create(file_path)?;
+        let mut writer = ArrowWriter::try_new(file, schema.clone(), Some(props))?;
+        let default_index = [(INDEX_NAME.to_string(), INDEX_NAME.to_string())];
+        if options.pandas_metadata || !(index.is_empty() || index == default_index) {
+            writer.append_key_value_metadata(KeyValue::new(
+                "pandas".to_string(),
+                pandas_metadata(&schema, index),
+            ));
         }
+        Ok(Self {
+            writer,
+            schema,
+            nan_as_null: options.nan_as_null,
+        })
+    }
 
-        values
+    /// Append rows; `columns` follow the schema order and have equal length
+    pub fn write_batch(&mut self, columns: Vec<Column>) -> Result<(), DsError> {
+        if columns.len() != self.schema.fields().len() {
+            return Err(DsError::InvalidArgument(format!(
+                "batch has {} columns, schema has {}",
+                columns.len(),
+                self.schema.fields().len()
+            )));
+        }
+        let arrays: Vec<ArrayRef> = columns
+            .into_iter()
+            .map(|col| column_array(col, self.nan_as_null))
+            .collect();
+        let batch = RecordBatch::try_new(self.schema.clone(), arrays)?;
+        self.writer.write(&batch)?;
+        Ok(())
     }
 
-    fn read_timestamp_second(&self) -> Vec<i64> {
-        use arrow::array::TimestampSecondArray;
-        use arrow::datatypes::{DataType, TimeUnit};
+    /// Close the current row group even if it is not full
+    pub fn flush(&mut self) -> Result<(), DsError> {
+        self.writer.flush()?;
+        Ok(())
+    }
 
-        const TIMESTAMP_COL: &str = "timestamp";
+    /// Write the remaining rows and the file footer
+    pub fn finish(self) -> Result<(), DsError> {
+        self.writer.close()?;
+        Ok(())
+    }
+}
 
-        let file = File::open(&self.path)
-            .unwrap_or_else(|e| panic!("failed to open parquet file '{}': {e}", self.path));
+/// Append rows to the Parquet file at `file_path`. The columns must match the
+/// file's schema in names, order and types (the index included), and may only
+/// hold NULL where the file's columns are nullable. The existing row groups are
+/// streamed batch by batch into a new file that replaces the old one; the new
+/// file is written with `options` (compression, row group size, ...), not the
+/// original file's settings.
+pub fn append_parquet(
+    file_path: &str,
+    columns: Vec<(String, Column)>,
+    options: &ParquetWriteOptions,
+) -> Result<(), DsError> {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(file_path)?)?;
+    let schema = builder.schema().clone();
+    check_append_schema(file_path, &schema, &columns_schema(&columns, options))?;
+    let reader = builder.build()?;
 
-        let builder = ParquetRecordBatchReaderBuilder::try_new(file)
-            .unwrap_or_else(|e| panic!("failed to build parquet reader: {e}"));
-        let mut reader = builder
-            .build()
-            .unwrap_or_else(|e| panic!("failed to build record batch reader: {e}"));
+    let tmp_path = format!("{}.tmp", file_path);
+    let result = (|| {
+        let mut writer = ParquetStreamWriter::open(&tmp_path, schema, options)?;
+        for batch in reader {
+            writer.writer.write(&batch?)?;
+        }
+        writer.write_batch(columns.into_iter().map(|(_, col)| col).collect())?;
+        writer.finish()
+    })();
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp_path);
+        return result;
+    }
 
-        let mut values: Vec<i64> = Vec::with_capacity(self.row_count);
-        let mut global_row: usize = 0;
+    std::fs::rename(&tmp_path, file_path)?;
+    Ok(())
+}
 
-        while let Some(batch_res) = reader.next() {
-            let batch = batch_res.unwrap_or_else(|e| panic!("error reading batch: {e}"));
+/// File name used by `append_parquet_dataset`; `{i}` is replaced by a
+/// zero-padded counter
+pub(crate) const DEFAULT_PART_NAME: &str = "part-{i}.parquet";
 
-            let schema = batch.schema();
-            let idx = schema
-                .index_of(TIMESTAMP_COL)
-                .unwrap_or_else(|_| panic!("timestamp column '{}' not found", TIMESTAMP_COL));
+/// Append rows to the dataset directory `dir` as a new file `part-NNNNN.parquet`.
+/// The columns must match the schema of the files already in the directory;
+/// an empty or missing directory starts a new dataset.
+pub fn append_parquet_dataset(
+    dir: &str,
+    columns: Vec<(String, Column)>,
+    options: &ParquetWriteOptions,
+) -> Result<String, DsError> {
+    append_dataset_file(Path::new(dir), DEFAULT_PART_NAME, columns, &[], options)
+}
 
-            // Validate dtype once per batch
-            let field = schema.field(idx);
-            match field.data_type() {
-                DataType::Timestamp(TimeUnit::Second, tz) if tz.as_deref() == Some("UTC") => {}
-                other => panic!(
-                    "timestamp column '{}' has dtype {:?}, expected Timestamp(Second, Some(\"UTC\"))",
-                    TIMESTAMP_COL, other
-                ),
-            }
+/// Write `columns` as a new file in `dir` named after `file_name`, taking the
+/// first free value of the `{i}` counter, after checking them against the
+/// schema of the files already there. `index` lists the columns that are
+/// index levels, as `(field, level name)` pairs.
+pub(crate) fn append_dataset_file(
+    dir: &Path,
+    file_name: &str,
+    columns: Vec<(String, Column)>,
+    index: &[(String, String)],
+    options: &ParquetWriteOptions,
+) -> Result<String, DsError> {
+    std::fs::create_dir_all(dir)?;
+    let existing = parquet_files_in(dir)?;
 
-            let col = batch
-                .column(idx)
-                .as_any()
-                .downcast_ref::<TimestampSecondArray>()
-                .unwrap_or_else(|| {
-                    panic!(
-                        "timestamp column '{}' is not a TimestampSecondArray",
-                        TIMESTAMP_COL
-                    )
-                });
+    let new_schema = columns_schema(&columns, options);
+    if let Some(first) = existing.first() {
+        let path = first.to_string_lossy();
+        let schema = ParquetRecordBatchReaderBuilder::try_new(File::open(first)?)?
+            .schema()
+            .clone();
+        check_append_schema(&path, &schema, &new_schema)?;
+    }
 
-            for (j, opt) in col.iter().enumerate() {
-                let ts = opt.unwrap_or_else(|| {
-                    panic!(
-                        "timestamp column '{}' contains a NULL at row {}",
-                        TIMESTAMP_COL,
-                        global_row + j
-                    )
-                });
-                values.push(ts);
-            }
+    let mut part = existing.len();
+    let file_path = loop {
+        let candidate = dir.join(file_name.replace("{i}", &format!("{:05}", part)));
+        if !candidate.exists() {
+            break candidate.to_string_lossy().to_string();
+        }
+        if !file_name.contains("{i}") {
+            return Err(DsError::InvalidArgument(format!(
+                "'{}' already exists",
+                candidate.display()
+            )));
+        }
+        part += 1;
+    };
+    write_columns(&file_path, columns, index, options)?;
+    Ok(file_path)
+}
 
-            global_row += col.len();
+/// Parquet files directly inside `dir`, sorted by name
+pub(crate) fn parquet_files_in(dir: &Path) -> Result<Vec<PathBuf>, DsError> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "parquet") {
+            files.push(path);
         }
+    }
+    files.sort();
+    Ok(files)
+}
 
-        values
+/// Check that rows with schema `new` can be appended to the file `path` with
+/// schema `existing`: same names and types, and no nullable column where the
+/// existing one is not
+fn check_append_schema(path: &str, existing: &Schema, new: &Schema) -> Result<(), DsError> {
+    let names = |schema: &Schema| -> Vec<String> {
+        schema
+            .fields()
+            .iter()
+            .map(|f| f.name().to_string())
+            .collect()
+    };
+    if names(existing) != names(new) {
+        return Err(DsError::SchemaMismatch {
+            path: path.to_string(),
+            message: format!(
+                "columns {:?} do not match existing columns {:?}",
+                names(new),
+                names(existing)
+            ),
+        });
     }
+    for (old, new) in existing.fields().iter().zip(new.fields()) {
+        if old.data_type() != new.data_type() {
+            return Err(DsError::SchemaMismatch {
+                path: path.to_string(),
+                message: format!(
+                    "column '{}' has type {:?}, existing type is {:?}",
+                    new.name(),
+                    new.data_type(),
+                    old.data_type()
+                ),
+            });
+        }
+        // NULLs, including NaN written as NULL, cannot go into a non-nullable column
+        if new.is_nullable() && !old.is_nullable() {
+            return Err(DsError::SchemaMismatch {
+                path: path.to_string(),
+                message: format!(
+                    "column '{}' may hold NULL, existing column is not nullable",
+                    new.name()
+                ),
+            });
+        }
+    }
+    Ok(())
 }
 
-pub fn write_parquet(
-    file_path: &str,
-    timestamps: Option<Vec<i64>>,
-    int_data: Option<HashMap<String, Vec<i64>>>,
-    float_data: Option<HashMap<String, Vec<f64>>>,
-    string_data: Option<HashMap<String, Vec<String>>>,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let mut fields = Vec::new();
-    let mut columns: Vec<ArrayRef> = Vec::new();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Add timestamp column only if provided
-    if let Some(ts) = &timestamps {
-        fields.push(Field::new(
+    const ROWS: i64 = 12_000;
+    const ROW_GROUP: usize = 4_000;
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("ds_util_{}_{}.parquet", name, std::process::id()))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    /// Writes `columns` with `pandas` as the file's pandas metadata, as
+    /// pandas itself would
+    fn write_pandas_file(path: &str, columns: Vec<(String, Column)>, pandas: &str) {
+        let schema = columns_schema(&columns, &ParquetWriteOptions::default());
+        let arrays = columns
+            .into_iter()
+            .map(|(_, column)| column_array(column, false))
+            .collect();
+        let batch = RecordBatch::try_new(schema.clone(), arrays).unwrap();
+        let mut writer = ArrowWriter::try_new(File::create(path).unwrap(), schema, None).unwrap();
+        writer.append_key_value_metadata(KeyValue::new("pandas".to_string(), pandas.to_string()));
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    /// A file with a `timestamp` column of one row per second in three row
+    /// groups, split into small pages, and `x` equal to the row number
+    fn write_window_file(name: &str, statistics: EnabledStatistics) -> String {
+        let path = temp_path(name);
+        let options = ParquetWriteOptions {
+            max_row_group_size: ROW_GROUP,
+            data_page_size: 1,
+            dictionary: false,
+            statistics,
+            ..ParquetWriteOptions::default()
+        };
+        let columns = vec![
+            (
+                "timestamp".to_string(),
+                Column::Timestamp(
+                    TimeUnit::Microsecond,
+                    (0..ROWS).map(|i| i * 1_000_000).collect(),
+                ),
+            ),
+            (
+                "x".to_string(),
+                Column::Float64((0..ROWS).map(|i| i as f64).collect()),
+            ),
+        ];
+        write_parquet_columns(&path, columns, &options).unwrap();
+        path
+    }
+
+    fn window_selection(path: &str, lo: i64, hi: i64) -> (Vec<usize>, RowSelection) {
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            File::open(path).unwrap(),
+            options,
+        )
+        .unwrap();
+        select_time_window(
+            builder.metadata(),
+            builder.schema(),
+            builder.parquet_schema(),
             "timestamp",
-            DataType::Timestamp(TimeUnit::Second, Some("UTC".into())),
-            false,
-        ));
-        columns.push(Arc::new(
-            TimestampSecondArray::from(ts.clone()).with_timezone("UTC"),
-        ));
+            lo,
+            hi,
+        )
+        .unwrap()
     }
 
-    // Unwrap options with defaults
-    let int_data = int_data.unwrap_or_default();
-    let float_data = float_data.unwrap_or_default();
-    let string_data = string_data.unwrap_or_default();
+    #[test]
+    fn window_prunes_row_groups_and_pages() {
+        let path = write_window_file("pages", EnabledStatistics::Page);
+        let (row_groups, selection) = window_selection(&path, 5_000_000_000, 5_100_000_000);
+        assert_eq!(row_groups, vec![1]);
+        // Only the pages around rows 5000..5100 of the second row group are read
+        assert_eq!(
+            selection.row_count() + selection.skipped_row_count(),
+            ROW_GROUP
+        );
+        assert!(selection.row_count() >= 100);
+        assert!(selection.row_count() < ROW_GROUP);
 
-    // Enum to track column type
-    enum ColumnType {
-        Int64,
-        Float64,
-        String,
+        let (index, columns) = read_parquet(&path)
+            .unwrap()
+            .read_window_f64(5_000_000_000, 5_100_000_000, &["x"])
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            index,
+            (5_000..5_100).map(|i| i * 1_000_000).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            columns,
+            vec![(5_000..5_100).map(|i| i as f64).collect::<Vec<_>>()]
+        );
     }
 
-    // Build a unified list of (name, type) pairs
-    let mut column_info: Vec<(&String, ColumnType)> = Vec::new();
-    for key in int_data.keys() {
-        column_info.push((key, ColumnType::Int64));
+    #[test]
+    fn window_spanning_row_groups_keeps_both() {
+        let path = write_window_file("span", EnabledStatistics::Page);
+        let (row_groups, _) = window_selection(&path, 3_990_000_000, 4_010_000_000);
+        let (index, _) = read_parquet(&path)
+            .unwrap()
+            .read_window_f64(3_990_000_000, 4_010_000_000, &["x"])
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(row_groups, vec![0, 1]);
+        assert_eq!(index.len(), 20);
     }
-    for key in float_data.keys() {
-        column_info.push((key, ColumnType::Float64));
+
+    #[test]
+    fn window_without_page_index_selects_whole_row_groups() {
+        let path = write_window_file("chunks", EnabledStatistics::Chunk);
+        let (row_groups, selection) = window_selection(&path, 9_000_000_000, 9_000_000_001);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(row_groups, vec![2]);
+        assert_eq!(selection.row_count(), ROW_GROUP);
+        assert_eq!(selection.skipped_row_count(), 0);
     }
-    for key in string_data.keys() {
-        column_info.push((key, ColumnType::String));
+
+    #[test]
+    fn window_reads_columns_of_any_type() {
+        let path = temp_path("window_types");
+        let columns = vec![
+            (
+                INDEX_NAME.to_string(),
+                Column::Timestamp(
+                    TimeUnit::Nanosecond,
+                    (0..6).map(|i| i * 1_000_000_000).collect(),
+                ),
+            ),
+            (
+                "label".to_string(),
+                Column::Utf8(["a", "b", "c", "d", "e", "f"].map(String::from).to_vec()),
+            ),
+            (
+                "n".to_string(),
+                Column::NullableInt64(vec![Some(0), None, Some(2), Some(3), None, Some(5)]),
+            ),
+        ];
+        write_parquet_columns(&path, columns, &ParquetWriteOptions::default()).unwrap();
+        let df = read_parquet(&path).unwrap();
+        let window = df.read_window(1_000_000, 4_000_000, &["n", "label"]);
+        let f64_error = df
+            .read_window_f64(1_000_000, 4_000_000, &["n"])
+            .unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        let (index, columns) = window.unwrap();
+        assert_eq!(index, vec![1_000_000, 2_000_000, 3_000_000]);
+        assert_eq!(
+            columns,
+            vec![
+                Column::NullableInt64(vec![None, Some(2), Some(3)]),
+                Column::Utf8(["b", "c", "d"].map(String::from).to_vec()),
+            ]
+        );
+        assert!(matches!(f64_error, DsError::TypeMismatch { .. }));
     }
 
-    // Sort alphanumerically by column name
-    column_info.sort_by(|a, b| alphanumeric_sort::compare_str(a.0, b.0));
-
-    // Add fields in sorted order
-    for (name, col_type) in &column_info {
-        let data_type = match col_type {
-            ColumnType::Int64 => DataType::Int64,
-            ColumnType::Float64 => DataType::Float64,
-            ColumnType::String => DataType::Utf8,
-        };
-        fields.push(Field::new(name.as_str(), data_type, false));
+    #[test]
+    fn window_outside_data_selects_nothing() {
+        let path = write_window_file("outside", EnabledStatistics::Page);
+        let (row_groups, selection) = window_selection(&path, -10, 0);
+        std::fs::remove_file(&path).unwrap();
+        assert!(row_groups.is_empty());
+        assert!(!selection.selects_any());
     }
 
-    let schema = Arc::new(Schema::new(fields));
+    #[test]
+    fn rewrite_keeps_stored_index_levels() {
+        let source = temp_path("index_source");
+        let copy = temp_path("index_copy");
+        let timestamps = vec![1_000_000_001, 2_000_000_002];
+        write_pandas_file(
+            &source,
+            vec![
+                (
+                    "date".to_string(),
+                    Column::Timestamp(TimeUnit::Nanosecond, timestamps.clone()),
+                ),
+                (
+                    "sym".to_string(),
+                    Column::Utf8(vec!["a".into(), "b".into()]),
+                ),
+                ("v".to_string(), Column::Float64(vec![1.0, 2.0])),
+            ],
+            r#"{"index_columns": ["date", "sym"], "columns": [
+                {"name": "date", "field_name": "date"},
+                {"name": "sym", "field_name": "sym"},
+                {"name": "v", "field_name": "v"}]}"#,
+        );
+        let df = read_parquet(&source).unwrap();
+        write_parquet_dataframe(&copy, df.as_ref(), &ParquetWriteOptions::default()).unwrap();
+        let copied = read_parquet(&copy).unwrap();
+        let (names, date, sym) = (
+            copied.index_names(),
+            copied.read_column("date"),
+            copied.read_column("sym"),
+        );
+        std::fs::remove_file(&source).unwrap();
+        std::fs::remove_file(&copy).unwrap();
+
+        assert_eq!(names, vec!["date", "sym"]);
+        assert_eq!(copied.column_names(), vec!["v"]);
+        assert_eq!(
+            date.unwrap(),
+            Column::Timestamp(TimeUnit::Nanosecond, timestamps)
+        );
+        assert_eq!(sym.unwrap(), Column::Utf8(vec!["a".into(), "b".into()]));
+    }
 
-    // Add data columns in the same sorted order
-    for (name, col_type) in &column_info {
-        let array: ArrayRef = match col_type {
-            ColumnType::Int64 => {
-                let data = int_data.get(*name).unwrap();
-                Arc::new(Int64Array::from(data.clone()))
-            }
-            ColumnType::Float64 => {
-                let data = float_data.get(*name).unwrap();
-                Arc::new(Float64Array::from(data.clone()))
-            }
-            ColumnType::String => {
-                let data = string_data.get(*name).unwrap();
-                Arc::new(StringArray::from(data.clone()))
-            }
+    #[test]
+    fn zero_row_group_size_is_rejected() {
+        let path = temp_path("zero_row_group");
+        let options = ParquetWriteOptions {
+            max_row_group_size: 0,
+            ..ParquetWriteOptions::default()
         };
-        columns.push(array);
+        let columns = vec![("x".to_string(), Column::Float64(vec![1.0]))];
+        let error = write_parquet_columns(&path, columns, &options).unwrap_err();
+        assert!(matches!(error, DsError::InvalidArgument(_)));
+        assert!(!Path::new(&path).exists());
     }
 
-    // Create record batch
-    let batch = RecordBatch::try_new(schema.clone(), columns)?;
+    #[test]
+    fn append_adds_rows_with_matching_schema() {
+        let path = temp_path("append_rows");
+        let options = ParquetWriteOptions::default();
+        let columns = |values: Vec<i64>| vec![("n".to_string(), Column::Int64(values))];
+        write_parquet_columns(&path, columns(vec![1, 2]), &options).unwrap();
+        append_parquet(&path, columns(vec![3]), &options).unwrap();
+        let values = read_parquet(&path).unwrap().read_column("n");
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(values.unwrap(), Column::Int64(vec![1, 2, 3]));
+    }
 
-    // Write to parquet file
-    let file = File::create(file_path)?;
-    let props = WriterProperties::builder().build();
-    let mut writer = ArrowWriter::try_new(file, schema, Some(props))?;
-    writer.write(&batch)?;
-    writer.close()?;
+    #[test]
+    fn append_rejects_different_schema() {
+        let path = temp_path("append_schema");
+        let options = ParquetWriteOptions::default();
+        let existing = vec![("n".to_string(), Column::Int64(vec![1, 2]))];
+        write_parquet_columns(&path, existing, &options).unwrap();
+        let attempts = [
+            ("m".to_string(), Column::Int64(vec![3])),
+            ("n".to_string(), Column::Float64(vec![3.0])),
+            ("n".to_string(), Column::NullableInt64(vec![None])),
+        ];
+        let errors: Vec<DsError> = attempts
+            .into_iter()
+            .map(|column| append_parquet(&path, vec![column], &options).unwrap_err())
+            .collect();
+        let values = read_parquet(&path).unwrap().read_column("n");
+        std::fs::remove_file(&path).unwrap();
 
-    Ok(())
+        for error in errors {
+            assert!(
+                matches!(error, DsError::SchemaMismatch { .. }),
+                "{:?}",
+                error
+            );
+        }
+        assert_eq!(values.unwrap(), Column::Int64(vec![1, 2]));
+    }
+
+    #[test]
+    fn dataset_append_checks_existing_files() {
+        let dir = std::env::temp_dir().join(format!("ds_util_append_dir_{}", std::process::id()));
+        let dir_name = dir.to_string_lossy().to_string();
+        let options = ParquetWriteOptions::default();
+        let first = append_parquet_dataset(
+            &dir_name,
+            vec![("n".to_string(), Column::Int64(vec![1]))],
+            &options,
+        );
+        let second = append_parquet_dataset(
+            &dir_name,
+            vec![("n".to_string(), Column::Int64(vec![2]))],
+            &options,
+        );
+        let mismatch = append_parquet_dataset(
+            &dir_name,
+            vec![("n".to_string(), Column::Utf8(vec!["x".to_string()]))],
+            &options,
+        );
+        let files = parquet_files_in(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(first.unwrap().ends_with("part-00000.parquet"));
+        assert!(second.unwrap().ends_with("part-00001.parquet"));
+        assert!(matches!(
+            mismatch.unwrap_err(),
+            DsError::SchemaMismatch { .. }
+        ));
+        assert_eq!(files.len(), 2);
+    }
+
+    #[test]
+    fn timestamps_read_from_dates_and_zoned_columns() {
+        use arrow::array::{Date32Array, Date64Array};
+
+        let path = temp_path("timestamp_types");
+        let day = 86_400;
+        let arrays: Vec<(&str, ArrayRef)> = vec![
+            ("d32", Arc::new(Date32Array::from(vec![1, -1]))),
+            ("d64", Arc::new(Date64Array::from(vec![day * 1_000, 1_500]))),
+            (
+                "ny",
+                Arc::new(
+                    TimestampMillisecondArray::from(vec![1_999, -1])
+                        .with_timezone("America/New_York"),
+                ),
+            ),
+        ];
+        let batch = RecordBatch::try_from_iter(arrays).unwrap();
+        let mut writer =
+            ArrowWriter::try_new(File::create(&path).unwrap(), batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let df = read_parquet(&path).unwrap();
+        let read = |name| df.read_column_timestamp(name, TimeUnit::Second);
+        let (d32, d64, ny) = (read("d32"), read("d64"), read("ny"));
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(d32.unwrap(), vec![day, -day]);
+        assert_eq!(d64.unwrap(), vec![day, 1]);
+        // Zoned values are UTC instants and come back unshifted
+        assert_eq!(ny.unwrap(), vec![1, -1]);
+    }
+
+    #[test]
+    fn pandas_index_columns_hide_stored_levels() {
+        let path = temp_path("pandas_index");
+        write_pandas_file(
+            &path,
+            vec![
+                (
+                    "__index_level_0__".to_string(),
+                    Column::Timestamp(TimeUnit::Millisecond, vec![1, 2]),
+                ),
+                ("when".to_string(), Column::Int64(vec![5, 6])),
+                ("v".to_string(), Column::Float64(vec![1.0, 2.0])),
+            ],
+            r#"{"index_columns": ["__index_level_0__", "when"], "columns": [
+                {"name": null, "field_name": "__index_level_0__"},
+                {"name": "date", "field_name": "when"},
+                {"name": "v", "field_name": "v"}]}"#,
+        );
+        let df = read_parquet(&path).unwrap();
+        let index = df.read_index_microsecond();
+        std::fs::remove_file(&path).unwrap();
+
+        // An unnamed level is called `__index_level_N__`, a named one keeps its name
+        assert_eq!(df.index_names(), vec!["__index_level_0__", "date"]);
+        assert_eq!(df.column_names(), vec!["v"]);
+        assert!(df.has_index());
+        assert_eq!(index.unwrap(), vec![1_000, 2_000]);
+    }
+
+    #[test]
+    fn pandas_range_index_is_computed() {
+        let path = temp_path("pandas_range");
+        write_pandas_file(
+            &path,
+            vec![("v".to_string(), Column::Float64(vec![1.0, 2.0, 3.0]))],
+            r#"{"index_columns": [{"kind": "range", "name": "row", "start": 10,
+                "stop": 16, "step": 2}], "columns": [{"name": "v", "field_name": "v"}]}"#,
+        );
+        let df = read_parquet(&path).unwrap();
+        let (rows, tail) = (df.read_column("row"), df.read_column_i64_range("row", 1..3));
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(df.index_names(), vec!["row"]);
+        assert_eq!(df.column_names(), vec!["v"]);
+        assert!(!df.has_index());
+        assert!(df.index_fields().is_empty());
+        assert_eq!(rows.unwrap(), Column::Int64(vec![10, 12, 14]));
+        assert_eq!(tail.unwrap(), vec![12, 14]);
+    }
+
+    #[test]
+    fn unusable_pandas_metadata_falls_back_to_default_index() {
+        let schema = Schema::new(vec![
+            Field::new(INDEX_NAME, DataType::Int64, false),
+            Field::new("v", DataType::Float64, false),
+        ]);
+        let stored = |levels: Option<Vec<IndexLevel>>| {
+            levels.map(|levels| {
+                levels
+                    .iter()
+                    .map(|l| l.name().to_string())
+                    .collect::<Vec<_>>()
+            })
+        };
+        assert_eq!(
+            stored(pandas_index_levels(r#"{"index_columns": ["v"]}"#, &schema)),
+            Some(vec!["__index_level_0__".to_string()])
+        );
+        // A missing field, an unknown level kind or bad JSON reject the metadata
+        for json in [
+            r#"{"index_columns": ["missing"]}"#,
+            r#"{"index_columns": [{"kind": "interval"}]}"#,
+            "not json",
+        ] {
+            assert!(pandas_index_levels(json, &schema).is_none(), "{}", json);
+        }
+        assert_eq!(
+            stored(Some(index_levels(&schema))),
+            Some(vec![INDEX_NAME.to_string()])
+        );
+    }
 }